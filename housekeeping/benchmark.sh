@@ -0,0 +1,43 @@
+#!/bin/bash
+
+# Regenerates the weights with the benchmark CLI. Run it from the repository root on the
+# reference hardware, optionally passing the pallets to benchmark:
+# ./housekeeping/benchmark.sh [pallet...]
+
+set -e
+
+BINARY=./target/release/parachain-collator
+
+# <pallet> <output> [template]
+WEIGHTS=(
+    "xcm_app pallets/xcm-app/src/weights.rs ./housekeeping/pallet-weight-template.hbs"
+    "pallet_xcm runtime/src/weights/pallet_xcm.rs"
+    "pallet_xcm_benchmarks::fungible runtime/src/weights/xcm/pallet_xcm_benchmarks_fungible.rs ./housekeeping/xcm-weight-template.hbs"
+    "pallet_xcm_benchmarks::generic runtime/src/weights/xcm/pallet_xcm_benchmarks_generic.rs ./housekeeping/xcm-weight-template.hbs"
+    "frame_system runtime/src/weights/frame_system.rs"
+    "pallet_balances runtime/src/weights/pallet_balances.rs"
+    "pallet_session runtime/src/weights/pallet_session.rs"
+    "pallet_timestamp runtime/src/weights/pallet_timestamp.rs"
+    "pallet_collator_selection runtime/src/weights/pallet_collator_selection.rs"
+    "cumulus_pallet_xcmp_queue runtime/src/weights/cumulus_pallet_xcmp_queue.rs"
+    "xcm_app_sudo_wrapper pallets/xcm-app-sudo-wrapper/src/weights.rs ./housekeeping/pallet-weight-template.hbs"
+)
+
+cargo build --release --locked --features runtime-benchmarks
+
+for weight in "${WEIGHTS[@]}"; do
+    read -r pallet output template <<< "$weight"
+    if [ $# -gt 0 ] && [[ ! " $* " =~ " $pallet " ]]; then
+        continue
+    fi
+    $BINARY benchmark pallet \
+        --chain dev \
+        --execution=wasm \
+        --wasm-execution=compiled \
+        --pallet "$pallet" \
+        --extrinsic '*' \
+        --steps 50 \
+        --repeat 20 \
+        ${template:+--template=$template} \
+        --output "$output"
+done
@@ -3,13 +3,14 @@ use cumulus_primitives_core::ParaId;
 use hex_literal::hex;
 use parachain_template_runtime::{
     AccountId, AuraId, BeefyId, BeefyLightClientConfig, MultisigVerifierConfig, Signature,
-    EXISTENTIAL_DEPOSIT,
+    XCMAppConfig, EXISTENTIAL_DEPOSIT,
 };
 use sc_chain_spec::{ChainSpecExtension, ChainSpecGroup};
 use sc_service::ChainType;
 use serde::{Deserialize, Serialize};
 use sp_core::{sr25519, ByteArray, Pair, Public};
 use sp_runtime::traits::{IdentifyAccount, Verify};
+use xcm::prelude::{MultiLocation, NetworkId};
 
 /// Specialized `ChainSpec` for the normal parachain runtime.
 pub type ChainSpec =
@@ -44,6 +45,7 @@ impl Extensions {
 
 type AccountPublic = <Signature as Verify>::Signer;
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum RelayChain {
     Kusama,
     Rococo,
@@ -61,6 +63,37 @@ pub fn dummy_beefy_id_from_account_id(a: AccountId) -> BeefyId {
 }
 
 impl RelayChain {
+    /// Relay chain of the runtime variant the node is built with
+    pub fn native() -> Self {
+        match parachain_template_runtime::variant::RELAY_NETWORK {
+            NetworkId::Polkadot => RelayChain::Polkadot,
+            NetworkId::Rococo => RelayChain::Rococo,
+            _ => RelayChain::Kusama,
+        }
+    }
+
+    /// Relay chain the chain spec with given id is made for, `None` for dev and local specs
+    pub fn from_chain_id(id: &str) -> Option<Self> {
+        if id.starts_with("sora_ksm") || id.starts_with("sora_kusama") {
+            Some(RelayChain::Kusama)
+        } else if id.starts_with("sora_dot") || id.starts_with("sora_polkadot") {
+            Some(RelayChain::Polkadot)
+        } else if id.starts_with("sora_rococo") {
+            Some(RelayChain::Rococo)
+        } else {
+            None
+        }
+    }
+
+    /// Cargo feature which builds the runtime for this relay chain
+    pub fn feature(&self) -> &'static str {
+        match self {
+            RelayChain::Kusama => "kusama",
+            RelayChain::Polkadot => "polkadot",
+            RelayChain::Rococo => "rococo",
+        }
+    }
+
     pub fn name(&self) -> &'static str {
         match self {
             RelayChain::Kusama => "SORA Kusama",
@@ -156,6 +189,14 @@ impl RelayChain {
         }
     }
 
+    pub fn relay_network(&self) -> NetworkId {
+        match self {
+            RelayChain::Kusama => NetworkId::Kusama,
+            RelayChain::Polkadot => NetworkId::Polkadot,
+            RelayChain::Rococo => NetworkId::Rococo,
+        }
+    }
+
     pub fn bridge_network_id(&self) -> SubNetworkId {
         match self {
             RelayChain::Kusama => SubNetworkId::Kusama,
@@ -222,6 +263,7 @@ pub fn coded_config(relay_chain: RelayChain, para_id: u32) -> ChainSpec {
     let session_keys = relay_chain.session_keys();
     let endowed_accounts = relay_chain.endowed_accounts();
     let bridge_network_id = relay_chain.bridge_network_id();
+    let relay_network = relay_chain.relay_network();
     ChainSpec::from_genesis(
         // Name
         relay_chain.name(),
@@ -235,6 +277,8 @@ pub fn coded_config(relay_chain: RelayChain, para_id: u32) -> ChainSpec {
                 endowed_accounts.clone(),
                 para_id.into(),
                 bridge_network_id,
+                relay_network,
+                SubNetworkId::Mainnet,
             )
         },
         Vec::new(),
@@ -289,6 +333,8 @@ pub fn development_config() -> ChainSpec {
                 ],
                 2011.into(),
                 SubNetworkId::Rococo,
+                NetworkId::Rococo,
+                SubNetworkId::Mainnet,
             )
         },
         Vec::new(),
@@ -350,6 +396,8 @@ pub fn local_testnet_config() -> ChainSpec {
                 ],
                 2011.into(),
                 SubNetworkId::Rococo,
+                NetworkId::Rococo,
+                SubNetworkId::Mainnet,
             )
         },
         // Bootnodes
@@ -402,6 +450,8 @@ pub fn docker_local_testnet_config() -> ChainSpec {
                 ],
                 2011.into(),
                 SubNetworkId::Rococo,
+                NetworkId::Rococo,
+                SubNetworkId::Mainnet,
             )
         },
         // Bootnodes
@@ -470,6 +520,8 @@ pub fn bridge_dev_config() -> ChainSpec {
                 ],
                 2011.into(),
                 SubNetworkId::Rococo,
+                NetworkId::Rococo,
+                SubNetworkId::Mainnet,
             )
         },
         // Bootnodes
@@ -496,6 +548,8 @@ fn testnet_genesis(
     endowed_accounts: Vec<AccountId>,
     id: ParaId,
     bridge_network_id: SubNetworkId,
+    relay_network: NetworkId,
+    sora_network: SubNetworkId,
 ) -> parachain_template_runtime::GenesisConfig {
     parachain_template_runtime::GenesisConfig {
         beefy_light_client: BeefyLightClientConfig { network_id: bridge_network_id },
@@ -511,6 +565,7 @@ fn testnet_genesis(
         balances: parachain_template_runtime::BalancesConfig {
             balances: endowed_accounts.iter().cloned().map(|k| (k, 1 << 60)).collect(),
         },
+        treasury: Default::default(),
         parachain_info: parachain_template_runtime::ParachainInfoConfig { parachain_id: id },
         collator_selection: parachain_template_runtime::CollatorSelectionConfig {
             invulnerables: invulnerables.iter().cloned().map(|(acc, _)| acc).collect(),
@@ -535,9 +590,20 @@ fn testnet_genesis(
         aura_ext: Default::default(),
         beefy: Default::default(),
         parachain_system: Default::default(),
+        maintenance_mode: Default::default(),
         polkadot_xcm: parachain_template_runtime::PolkadotXcmConfig {
             safe_xcm_version: Some(SAFE_XCM_VERSION),
         },
         sudo: parachain_template_runtime::SudoConfig { key: Some(root_key) },
+        // Governance is set up by sudo, after that sudo is removed with `RemoveSudo` migration
+        council: Default::default(),
+        council_membership: Default::default(),
+        technical_committee: Default::default(),
+        technical_membership: Default::default(),
+        xcm_app: XCMAppConfig {
+            relay_network,
+            sora_network,
+            allowed_destinations: vec![MultiLocation::parent()],
+        },
     }
 }
@@ -26,7 +26,7 @@ fn set_default_ss58_version() {
 }
 
 fn load_spec(id: &str) -> std::result::Result<Box<dyn ChainSpec>, String> {
-    Ok(match id {
+    let spec: Box<dyn ChainSpec> = match id {
         "dev" => Box::new(chain_spec::development_config()),
         "bridge-dev" => Box::new(chain_spec::bridge_dev_config()),
         "kusama" => Box::new(chain_spec::kusama_chain_spec()?),
@@ -39,12 +39,23 @@ fn load_spec(id: &str) -> std::result::Result<Box<dyn ChainSpec>, String> {
         "" | "local" => Box::new(chain_spec::local_testnet_config()),
         "docker-local" => Box::new(chain_spec::docker_local_testnet_config()),
         path => Box::new(chain_spec::ChainSpec::from_json_file(std::path::PathBuf::from(path))?),
-    })
+    };
+    match RelayChain::from_chain_id(spec.id()) {
+        Some(relay_chain) if relay_chain != RelayChain::native() => Err(format!(
+            "Chain spec `{}` requires {} runtime, but the node is built with {} runtime. \
+            Rebuild the node with `--features {}`",
+            spec.id(),
+            relay_chain.name(),
+            RelayChain::native().name(),
+            relay_chain.feature(),
+        )),
+        _ => Ok(spec),
+    }
 }
 
 impl SubstrateCli for Cli {
     fn impl_name() -> String {
-        "SORA Kusama Parachain Node".into()
+        format!("{} Parachain Node", RelayChain::native().name())
     }
 
     fn impl_version() -> String {
@@ -52,11 +63,13 @@ impl SubstrateCli for Cli {
     }
 
     fn description() -> String {
-        "SORA Kusama Parachain Node\n\nThe command-line arguments provided first will be \
+        format!(
+            "{} Parachain Node\n\nThe command-line arguments provided first will be \
 		passed to the parachain node, while the arguments provided after -- will be passed \
 		to the relay chain node.\n\n\
-		parachain-collator <parachain-args> -- <relay-chain-args>"
-            .into()
+		parachain-collator <parachain-args> -- <relay-chain-args>",
+            RelayChain::native().name()
+        )
     }
 
     fn author() -> String {
@@ -75,7 +88,8 @@ impl SubstrateCli for Cli {
         load_spec(id)
     }
 
-    fn native_runtime_version(_: &Box<dyn ChainSpec>) -> &'static RuntimeVersion {
+    fn native_runtime_version(_chain_spec: &Box<dyn ChainSpec>) -> &'static RuntimeVersion {
+        // `load_spec` rejects the chain specs of the other runtime variants
         &parachain_template_runtime::VERSION
     }
 }
@@ -0,0 +1,192 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Asset fee payment lets accounts which only hold bridged assets pay for their
+//! extrinsics. The fee is computed by `pallet_transaction_payment` in the native currency
+//! and converted into the chosen asset at the rate set by governance.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+mod payment;
+
+pub mod weights;
+
+pub use pallet::*;
+pub use payment::*;
+
+use frame_support::{traits::Contains, weights::Weight};
+use orml_traits::MultiCurrency;
+use pallet_transaction_payment::OnChargeTransaction;
+use parachain_common::primitives::AssetId;
+use sp_runtime::{
+    traits::Zero,
+    transaction_validity::{InvalidTransaction, TransactionValidityError},
+    FixedPointNumber, FixedPointOperand, FixedU128,
+};
+
+pub type BalanceOf<T> = <<T as pallet_transaction_payment::Config>::OnChargeTransaction as OnChargeTransaction<T>>::Balance;
+
+pub trait WeightInfo {
+    fn set_fee_rate() -> Weight;
+}
+
+/// Handler of the fees paid in assets, the fee is already withdrawn from the payer
+pub trait OnAssetFee<Balance> {
+    fn on_asset_fee(asset_id: AssetId, amount: Balance);
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::pallet_prelude::*;
+    use frame_system::pallet_prelude::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config + pallet_transaction_payment::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        type WeightInfo: WeightInfo;
+
+        /// Ledger of the assets which are used to pay fees
+        type Assets: MultiCurrency<Self::AccountId, CurrencyId = AssetId, Balance = BalanceOf<Self>>;
+
+        /// Assets accepted for fees, expected to be the assets mapped in `xcm_app`
+        type FeeAssets: Contains<AssetId>;
+
+        /// Receives the fees paid in assets
+        type OnAssetFee: OnAssetFee<BalanceOf<Self>>;
+
+        /// Origin allowed to set the fee rates
+        type ManagerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+    }
+
+    #[pallet::pallet]
+    #[pallet::generate_store(pub(super) trait Store)]
+    pub struct Pallet<T>(_);
+
+    /// Amount of the asset units charged per native currency unit
+    #[pallet::storage]
+    #[pallet::getter(fn fee_rate)]
+    pub type FeeRates<T: Config> = StorageMap<_, Blake2_128Concat, AssetId, FixedU128, OptionQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Fee rate of the asset has been set, `None` disables paying fees with the asset
+        /// [AssetId, Rate]
+        FeeRateChanged(AssetId, Option<FixedU128>),
+        /// Transaction fee has been paid in the asset
+        /// [Who, AssetId, Amount]
+        AssetTxFeePaid(T::AccountId, AssetId, BalanceOf<T>),
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Fee rate can not be zero
+        ZeroFeeRate,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Set the rate at which the asset is charged instead of the native currency
+        #[pallet::call_index(0)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_fee_rate())]
+        pub fn set_fee_rate(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+            rate: Option<FixedU128>,
+        ) -> DispatchResultWithPostInfo {
+            T::ManagerOrigin::ensure_origin(origin)?;
+            ensure!(rate != Some(Zero::zero()), Error::<T>::ZeroFeeRate);
+            FeeRates::<T>::set(asset_id, rate);
+            Self::deposit_event(Event::<T>::FeeRateChanged(asset_id, rate));
+            Ok(().into())
+        }
+    }
+}
+
+impl<T: Config> Pallet<T>
+where
+    BalanceOf<T>: FixedPointOperand,
+{
+    /// Amount of the asset charged instead of the given native fee, `None` if the asset
+    /// can not be used to pay fees
+    pub fn convert_fee(asset_id: AssetId, native_fee: BalanceOf<T>) -> Option<BalanceOf<T>> {
+        if !T::FeeAssets::contains(&asset_id) {
+            return None;
+        }
+        Self::fee_rate(asset_id).map(|rate| rate.saturating_mul_int(native_fee))
+    }
+
+    /// Withdraw the native fee converted into the asset, returns the withdrawn amount
+    pub(crate) fn withdraw_asset_fee(
+        who: &T::AccountId,
+        asset_id: AssetId,
+        native_fee: BalanceOf<T>,
+    ) -> Result<BalanceOf<T>, TransactionValidityError> {
+        let amount = Self::convert_fee(asset_id, native_fee).ok_or(InvalidTransaction::Payment)?;
+        T::Assets::withdraw(asset_id, who, amount).map_err(|_| InvalidTransaction::Payment)?;
+        Ok(amount)
+    }
+
+    /// Refund the part of the withdrawn amount which exceeds the actual fee and pass the
+    /// rest to the fee collector
+    pub(crate) fn settle_asset_fee(
+        who: &T::AccountId,
+        asset_id: AssetId,
+        paid: BalanceOf<T>,
+        native_fee: BalanceOf<T>,
+        actual_native_fee: BalanceOf<T>,
+    ) -> Result<(), TransactionValidityError> {
+        let charged = if native_fee.is_zero() {
+            paid
+        } else {
+            FixedU128::saturating_from_rational(actual_native_fee, native_fee)
+                .saturating_mul_int(paid)
+                .min(paid)
+        };
+        let refund = paid.saturating_sub(charged);
+        if !refund.is_zero() {
+            T::Assets::deposit(asset_id, who, refund).map_err(|_| InvalidTransaction::Payment)?;
+        }
+        if !charged.is_zero() {
+            T::OnAssetFee::on_asset_fee(asset_id, charged);
+        }
+        Self::deposit_event(Event::<T>::AssetTxFeePaid(who.clone(), asset_id, charged));
+        Ok(())
+    }
+}
@@ -0,0 +1,175 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use crate::{BalanceOf, Config, Pallet};
+use codec::{Decode, Encode};
+use frame_support::dispatch::{DispatchInfo, DispatchResult, PostDispatchInfo};
+use pallet_transaction_payment::{ChargeTransactionPayment, OnChargeTransaction};
+use parachain_common::primitives::AssetId;
+use scale_info::TypeInfo;
+use sp_runtime::{
+    traits::{DispatchInfoOf, Dispatchable, PostDispatchInfoOf, SignedExtension, Zero},
+    transaction_validity::{TransactionValidity, TransactionValidityError, ValidTransaction},
+    FixedPointOperand,
+};
+
+type LiquidityInfoOf<T> = <<T as pallet_transaction_payment::Config>::OnChargeTransaction as OnChargeTransaction<T>>::LiquidityInfo;
+
+/// Fee withdrawn before the dispatch
+pub enum InitialPayment<T: Config> {
+    /// Fee is zero
+    Nothing,
+    /// Fee is paid in the native currency
+    Native(LiquidityInfoOf<T>),
+    /// Fee is paid in the asset, `native_fee` is the fee the withdrawn `amount` stands for
+    Asset { asset_id: AssetId, native_fee: BalanceOf<T>, amount: BalanceOf<T> },
+}
+
+/// Replacement of `pallet_transaction_payment::ChargeTransactionPayment` which charges the
+/// fee in the native currency if `asset_id` is `None` and in the given asset otherwise.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct ChargeAssetTxPayment<T: Config> {
+    #[codec(compact)]
+    tip: BalanceOf<T>,
+    asset_id: Option<AssetId>,
+}
+
+impl<T: Config> ChargeAssetTxPayment<T>
+where
+    T::RuntimeCall: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+    BalanceOf<T>: Send + Sync + From<u64> + FixedPointOperand,
+{
+    pub fn from(tip: BalanceOf<T>, asset_id: Option<AssetId>) -> Self {
+        Self { tip, asset_id }
+    }
+
+    fn withdraw_fee(
+        &self,
+        who: &T::AccountId,
+        call: &T::RuntimeCall,
+        info: &DispatchInfoOf<T::RuntimeCall>,
+        len: usize,
+    ) -> Result<(BalanceOf<T>, InitialPayment<T>), TransactionValidityError> {
+        let fee = pallet_transaction_payment::Pallet::<T>::compute_fee(len as u32, info, self.tip);
+        if fee.is_zero() {
+            return Ok((fee, InitialPayment::Nothing));
+        }
+        match self.asset_id {
+            None => <T::OnChargeTransaction as OnChargeTransaction<T>>::withdraw_fee(
+                who, call, info, fee, self.tip,
+            )
+            .map(|liquidity_info| (fee, InitialPayment::Native(liquidity_info))),
+            Some(asset_id) => Pallet::<T>::withdraw_asset_fee(who, asset_id, fee)
+                .map(|amount| (fee, InitialPayment::Asset { asset_id, native_fee: fee, amount })),
+        }
+    }
+}
+
+impl<T: Config> sp_std::fmt::Debug for ChargeAssetTxPayment<T> {
+    #[cfg(feature = "std")]
+    fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+        write!(f, "ChargeAssetTxPayment<{:?}, {:?}>", self.tip, self.asset_id)
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn fmt(&self, _: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+        Ok(())
+    }
+}
+
+impl<T: Config> SignedExtension for ChargeAssetTxPayment<T>
+where
+    T::RuntimeCall: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+    BalanceOf<T>: Send + Sync + From<u64> + FixedPointOperand,
+{
+    const IDENTIFIER: &'static str = "ChargeAssetTxPayment";
+    type AccountId = T::AccountId;
+    type Call = T::RuntimeCall;
+    type AdditionalSigned = ();
+    type Pre = (BalanceOf<T>, T::AccountId, InitialPayment<T>);
+
+    fn additional_signed(&self) -> Result<Self::AdditionalSigned, TransactionValidityError> {
+        Ok(())
+    }
+
+    fn validate(
+        &self,
+        who: &Self::AccountId,
+        call: &Self::Call,
+        info: &DispatchInfoOf<Self::Call>,
+        len: usize,
+    ) -> TransactionValidity {
+        let (fee, _) = self.withdraw_fee(who, call, info, len)?;
+        let priority = ChargeTransactionPayment::<T>::get_priority(info, len, self.tip, fee);
+        Ok(ValidTransaction { priority, ..Default::default() })
+    }
+
+    fn pre_dispatch(
+        self,
+        who: &Self::AccountId,
+        call: &Self::Call,
+        info: &DispatchInfoOf<Self::Call>,
+        len: usize,
+    ) -> Result<Self::Pre, TransactionValidityError> {
+        let (_, initial_payment) = self.withdraw_fee(who, call, info, len)?;
+        Ok((self.tip, who.clone(), initial_payment))
+    }
+
+    fn post_dispatch(
+        pre: Option<Self::Pre>,
+        info: &DispatchInfoOf<Self::Call>,
+        post_info: &PostDispatchInfoOf<Self::Call>,
+        len: usize,
+        result: &DispatchResult,
+    ) -> Result<(), TransactionValidityError> {
+        if let Some((tip, who, initial_payment)) = pre {
+            match initial_payment {
+                InitialPayment::Native(liquidity_info) => {
+                    ChargeTransactionPayment::<T>::post_dispatch(
+                        Some((tip, who, liquidity_info)),
+                        info,
+                        post_info,
+                        len,
+                        result,
+                    )?;
+                },
+                InitialPayment::Asset { asset_id, native_fee, amount } => {
+                    let actual_fee = pallet_transaction_payment::Pallet::<T>::compute_actual_fee(
+                        len as u32, info, post_info, tip,
+                    );
+                    Pallet::<T>::settle_asset_fee(&who, asset_id, amount, native_fee, actual_fee)?;
+                },
+                InitialPayment::Nothing => {},
+            }
+        }
+        Ok(())
+    }
+}
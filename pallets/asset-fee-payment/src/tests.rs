@@ -0,0 +1,183 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use crate::{mock::*, ChargeAssetTxPayment, Error, Event};
+use frame_support::{
+    assert_noop, assert_ok,
+    dispatch::{DispatchInfo, PostDispatchInfo},
+    weights::Weight,
+};
+use orml_traits::MultiCurrency;
+use parachain_common::primitives::AssetId;
+use sp_runtime::{
+    traits::SignedExtension,
+    transaction_validity::{InvalidTransaction, TransactionValidityError},
+    DispatchError, FixedPointNumber, FixedU128,
+};
+
+const CALL_LEN: usize = 10;
+
+fn remark_call() -> RuntimeCall {
+    RuntimeCall::System(frame_system::Call::remark { remark: vec![] })
+}
+
+/// Dispatch info of a call which costs 100 with the base weight and the length fee
+fn info() -> DispatchInfo {
+    DispatchInfo { weight: Weight::from_ref_time(80), ..Default::default() }
+}
+
+/// Post dispatch info of a call which actually costs 50
+fn post_info() -> PostDispatchInfo {
+    PostDispatchInfo {
+        actual_weight: Some(Weight::from_ref_time(30)),
+        pays_fee: Default::default(),
+    }
+}
+
+fn charge(who: AccountId, asset_id: Option<AssetId>) -> Result<(), TransactionValidityError> {
+    let pre = ChargeAssetTxPayment::<Test>::from(0, asset_id).pre_dispatch(
+        &who,
+        &remark_call(),
+        &info(),
+        CALL_LEN,
+    )?;
+    ChargeAssetTxPayment::<Test>::post_dispatch(Some(pre), &info(), &post_info(), CALL_LEN, &Ok(()))
+}
+
+#[test]
+fn it_works_set_fee_rate() {
+    new_test_ext().execute_with(|| {
+        let rate = Some(FixedU128::from(2));
+        assert_ok!(AssetFeePayment::set_fee_rate(RuntimeOrigin::root(), FEE_ASSET, rate));
+        assert_eq!(AssetFeePayment::fee_rate(FEE_ASSET), rate);
+        System::assert_last_event(Event::<Test>::FeeRateChanged(FEE_ASSET, rate).into());
+
+        assert_ok!(AssetFeePayment::set_fee_rate(RuntimeOrigin::root(), FEE_ASSET, None));
+        assert_eq!(AssetFeePayment::fee_rate(FEE_ASSET), None);
+    });
+}
+
+#[test]
+fn it_fails_set_fee_rate_zero_or_not_root() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            AssetFeePayment::set_fee_rate(
+                RuntimeOrigin::root(),
+                FEE_ASSET,
+                Some(FixedU128::from(0))
+            ),
+            Error::<Test>::ZeroFeeRate
+        );
+        assert_noop!(
+            AssetFeePayment::set_fee_rate(
+                RuntimeOrigin::signed(ALICE),
+                FEE_ASSET,
+                Some(FixedU128::from(2))
+            ),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn it_works_convert_fee() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(AssetFeePayment::convert_fee(FEE_ASSET, 100), None);
+        assert_ok!(AssetFeePayment::set_fee_rate(
+            RuntimeOrigin::root(),
+            FEE_ASSET,
+            Some(FixedU128::saturating_from_rational(3, 2))
+        ));
+        assert_eq!(AssetFeePayment::convert_fee(FEE_ASSET, 100), Some(150));
+    });
+}
+
+#[test]
+fn it_works_pay_fee_in_native_currency() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(charge(ALICE, None));
+        assert_eq!(Balances::free_balance(ALICE), 950);
+        assert_eq!(Tokens::free_balance(FEE_ASSET, &ALICE), 1_000);
+    });
+}
+
+#[test]
+fn it_works_pay_fee_in_asset_with_refund() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(AssetFeePayment::set_fee_rate(
+            RuntimeOrigin::root(),
+            FEE_ASSET,
+            Some(FixedU128::from(2))
+        ));
+        let pre = ChargeAssetTxPayment::<Test>::from(0, Some(FEE_ASSET))
+            .pre_dispatch(&ALICE, &remark_call(), &info(), CALL_LEN)
+            .unwrap();
+        assert_eq!(Tokens::free_balance(FEE_ASSET, &ALICE), 800);
+
+        assert_ok!(ChargeAssetTxPayment::<Test>::post_dispatch(
+            Some(pre),
+            &info(),
+            &post_info(),
+            CALL_LEN,
+            &Ok(())
+        ));
+        assert_eq!(Tokens::free_balance(FEE_ASSET, &ALICE), 900);
+        assert_eq!(Tokens::free_balance(FEE_ASSET, &FEE_COLLECTOR), 100);
+        assert_eq!(Balances::free_balance(ALICE), 1_000);
+        System::assert_last_event(Event::<Test>::AssetTxFeePaid(ALICE, FEE_ASSET, 100).into());
+    });
+}
+
+#[test]
+fn it_fails_pay_fee_in_unsupported_asset() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(charge(ALICE, Some(FEE_ASSET)), Err(InvalidTransaction::Payment.into()));
+        assert_ok!(AssetFeePayment::set_fee_rate(
+            RuntimeOrigin::root(),
+            UNMAPPED_ASSET,
+            Some(FixedU128::from(2))
+        ));
+        assert_eq!(charge(ALICE, Some(UNMAPPED_ASSET)), Err(InvalidTransaction::Payment.into()));
+        assert_eq!(Tokens::free_balance(UNMAPPED_ASSET, &ALICE), 1_000);
+    });
+}
+
+#[test]
+fn it_fails_pay_fee_with_insufficient_asset_balance() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(AssetFeePayment::set_fee_rate(
+            RuntimeOrigin::root(),
+            FEE_ASSET,
+            Some(FixedU128::from(2))
+        ));
+        assert_eq!(charge(BOB, Some(FEE_ASSET)), Err(InvalidTransaction::Payment.into()));
+        assert_eq!(Balances::free_balance(BOB), 1_000);
+    });
+}
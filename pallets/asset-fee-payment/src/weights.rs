@@ -0,0 +1,49 @@
+
+//! Weights for `asset_fee_payment`
+//!
+//! NOT GENERATED: the values are estimated by hand from the storage accesses of the calls
+//! and have not been measured. Regenerate them on the reference hardware with the command
+//! below before relying on them.
+
+// Command to generate:
+// ./target/release/parachain-collator
+// benchmark
+// pallet
+// --chain
+// dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --pallet
+// asset_fee_payment
+// --extrinsic
+// *
+// --steps
+// 50
+// --repeat
+// 20
+// --output
+// pallets/asset-fee-payment/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+use parachain_common::primitives::EXTRINSIC_FIXED_WEIGHT;
+
+/// Weight functions for `asset_fee_payment`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
+	// Storage: AssetFeePayment FeeRates (r:0 w:1)
+	fn set_fee_rate() -> Weight {
+		Weight::from_ref_time(14_127_000)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+}
+
+impl crate::WeightInfo for () {
+	fn set_fee_rate() -> Weight {
+		EXTRINSIC_FIXED_WEIGHT
+	}
+}
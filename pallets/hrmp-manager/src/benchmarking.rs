@@ -0,0 +1,88 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::*;
+use crate::Pallet as HrmpManagerPallet;
+use frame_benchmarking::{benchmarks, BenchmarkError};
+use frame_support::traits::{EnsureOrigin, Get};
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+    frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+fn sibling<T: Config>() -> ParaId {
+    ParaId::from(u32::from(T::SelfParaId::get()) + 1)
+}
+
+benchmarks! {
+    request_open_channel {
+        let origin = T::ManagerOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        let recipient = sibling::<T>();
+    }: _<T::RuntimeOrigin>(origin, recipient, 8, 1024)
+    verify {
+        assert_last_event::<T>(Event::<T>::ChannelOpenRequested(recipient, 8, 1024).into());
+        assert_eq!(
+            HrmpManagerPallet::<T>::channel_state(recipient, ChannelDirection::Outbound),
+            Some(ChannelState::OpenRequested)
+        );
+    }
+
+    accept_open_channel {
+        let origin = T::ManagerOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        let sender = sibling::<T>();
+    }: _<T::RuntimeOrigin>(origin, sender)
+    verify {
+        assert_last_event::<T>(Event::<T>::ChannelAccepted(sender).into());
+        assert_eq!(
+            HrmpManagerPallet::<T>::channel_state(sender, ChannelDirection::Inbound),
+            Some(ChannelState::Accepted)
+        );
+    }
+
+    close_channel {
+        let origin = T::ManagerOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        let sibling = sibling::<T>();
+        Channels::<T>::insert(sibling, ChannelDirection::Outbound, ChannelState::OpenRequested);
+    }: _<T::RuntimeOrigin>(origin, sibling, ChannelDirection::Outbound)
+    verify {
+        assert_last_event::<T>(
+            Event::<T>::ChannelCloseRequested(sibling, ChannelDirection::Outbound).into()
+        );
+        assert_eq!(
+            HrmpManagerPallet::<T>::channel_state(sibling, ChannelDirection::Outbound),
+            Some(ChannelState::CloseRequested)
+        );
+    }
+
+    impl_benchmark_test_suite!(HrmpManagerPallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,315 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub mod weights;
+
+pub use pallet::*;
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use cumulus_primitives_core::{ChannelStatus, GetChannelInfo, ParaId};
+use frame_support::{weights::Weight, RuntimeDebug};
+use polkadot_parachain::primitives::HrmpChannelId;
+use scale_info::TypeInfo;
+use sp_std::{prelude::*, vec};
+use xcm::latest::prelude::*;
+
+pub trait WeightInfo {
+    fn request_open_channel() -> Weight;
+    fn accept_open_channel() -> Weight;
+    fn close_channel() -> Weight;
+}
+
+/// Direction of the HRMP channel as seen from this parachain
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum ChannelDirection {
+    /// Messages are sent from this parachain to the sibling
+    Outbound,
+    /// Messages are sent from the sibling to this parachain
+    Inbound,
+}
+
+/// Last request sent to the relay chain for the channel
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum ChannelState {
+    /// Opening of the outbound channel is requested and waits for the sibling to accept it
+    OpenRequested,
+    /// Opening of the inbound channel requested by the sibling is accepted
+    Accepted,
+    /// Closing of the channel is requested
+    CloseRequested,
+}
+
+/// HRMP channel tracked by the pallet
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ChannelInfo {
+    pub sibling: ParaId,
+    pub direction: ChannelDirection,
+    pub state: ChannelState,
+    /// Whether the channel is open on the relay chain, only known for the outbound channels
+    pub is_open: Option<bool>,
+}
+
+/// Calls of the relay chain `hrmp` pallet, the pallet index is prepended when encoding
+#[derive(Encode, Decode, RuntimeDebug)]
+pub enum HrmpCall {
+    #[codec(index = 0)]
+    InitOpenChannel(ParaId, u32, u32),
+    #[codec(index = 1)]
+    AcceptOpenChannel(ParaId),
+    #[codec(index = 2)]
+    CloseChannel(HrmpChannelId),
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::pallet_prelude::*;
+    use frame_system::pallet_prelude::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        type WeightInfo: WeightInfo;
+
+        /// Origin allowed to manage the channels
+        type ManagerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Id of this parachain
+        type SelfParaId: Get<ParaId>;
+
+        /// Router sending the `Transact` messages to the relay chain
+        type XcmSender: SendXcm;
+
+        /// Status of the outbound channels on the relay chain
+        type ChannelInfo: GetChannelInfo;
+
+        /// Index of the `hrmp` pallet in the relay chain runtime
+        #[pallet::constant]
+        type HrmpPalletIndex: Get<u8>;
+
+        /// Amount of the relay chain token withdrawn from the sovereign account of this
+        /// parachain to pay for the `Transact` execution, the surplus is refunded
+        #[pallet::constant]
+        type RelayFee: Get<u128>;
+
+        /// Weight of the `hrmp` calls on the relay chain
+        #[pallet::constant]
+        type TransactWeight: Get<Weight>;
+    }
+
+    #[pallet::pallet]
+    #[pallet::generate_store(pub(super) trait Store)]
+    pub struct Pallet<T>(_);
+
+    /// Channels with the siblings and the last request sent for them
+    #[pallet::storage]
+    #[pallet::getter(fn channel_state)]
+    pub type Channels<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        ParaId,
+        Twox64Concat,
+        ChannelDirection,
+        ChannelState,
+        OptionQuery,
+    >;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Opening of the outbound channel is requested
+        /// [Recipient, MaxCapacity, MaxMessageSize]
+        ChannelOpenRequested(ParaId, u32, u32),
+        /// Opening of the inbound channel is accepted
+        /// [Sender]
+        ChannelAccepted(ParaId),
+        /// Closing of the channel is requested
+        /// [Sibling, Direction]
+        ChannelCloseRequested(ParaId, ChannelDirection),
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Channel can not be opened with this parachain itself
+        InvalidSibling,
+        /// Channel is already requested or accepted
+        ChannelAlreadyRequested,
+        /// Channel is not tracked or is already being closed
+        ChannelNotFound,
+        /// Message to the relay chain can not be sent
+        SendFailure,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Request the relay chain to open the channel to the sibling
+        #[pallet::call_index(0)]
+        #[pallet::weight(<T as Config>::WeightInfo::request_open_channel())]
+        pub fn request_open_channel(
+            origin: OriginFor<T>,
+            recipient: ParaId,
+            max_capacity: u32,
+            max_message_size: u32,
+        ) -> DispatchResultWithPostInfo {
+            T::ManagerOrigin::ensure_origin(origin)?;
+            Self::ensure_can_open(recipient, ChannelDirection::Outbound)?;
+            Self::send_hrmp_call(HrmpCall::InitOpenChannel(
+                recipient,
+                max_capacity,
+                max_message_size,
+            ))?;
+            Channels::<T>::insert(
+                recipient,
+                ChannelDirection::Outbound,
+                ChannelState::OpenRequested,
+            );
+            Self::deposit_event(Event::<T>::ChannelOpenRequested(
+                recipient,
+                max_capacity,
+                max_message_size,
+            ));
+            Ok(().into())
+        }
+
+        /// Accept the channel requested by the sibling
+        #[pallet::call_index(1)]
+        #[pallet::weight(<T as Config>::WeightInfo::accept_open_channel())]
+        pub fn accept_open_channel(
+            origin: OriginFor<T>,
+            sender: ParaId,
+        ) -> DispatchResultWithPostInfo {
+            T::ManagerOrigin::ensure_origin(origin)?;
+            Self::ensure_can_open(sender, ChannelDirection::Inbound)?;
+            Self::send_hrmp_call(HrmpCall::AcceptOpenChannel(sender))?;
+            Channels::<T>::insert(sender, ChannelDirection::Inbound, ChannelState::Accepted);
+            Self::deposit_event(Event::<T>::ChannelAccepted(sender));
+            Ok(().into())
+        }
+
+        /// Request the relay chain to close the channel with the sibling
+        #[pallet::call_index(2)]
+        #[pallet::weight(<T as Config>::WeightInfo::close_channel())]
+        pub fn close_channel(
+            origin: OriginFor<T>,
+            sibling: ParaId,
+            direction: ChannelDirection,
+        ) -> DispatchResultWithPostInfo {
+            T::ManagerOrigin::ensure_origin(origin)?;
+            ensure!(
+                matches!(
+                    Self::channel_state(sibling, direction),
+                    Some(ChannelState::OpenRequested | ChannelState::Accepted)
+                ),
+                Error::<T>::ChannelNotFound
+            );
+            let self_id = T::SelfParaId::get();
+            let channel_id = match direction {
+                ChannelDirection::Outbound => HrmpChannelId { sender: self_id, recipient: sibling },
+                ChannelDirection::Inbound => HrmpChannelId { sender: sibling, recipient: self_id },
+            };
+            Self::send_hrmp_call(HrmpCall::CloseChannel(channel_id))?;
+            Channels::<T>::insert(sibling, direction, ChannelState::CloseRequested);
+            Self::deposit_event(Event::<T>::ChannelCloseRequested(sibling, direction));
+            Ok(().into())
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Channels tracked by the pallet
+    pub fn channels() -> Vec<ChannelInfo> {
+        Channels::<T>::iter()
+            .map(|(sibling, direction, state)| ChannelInfo {
+                sibling,
+                direction,
+                state,
+                is_open: match direction {
+                    ChannelDirection::Outbound => Some(!matches!(
+                        T::ChannelInfo::get_channel_status(sibling),
+                        ChannelStatus::Closed
+                    )),
+                    ChannelDirection::Inbound => None,
+                },
+            })
+            .collect()
+    }
+
+    fn ensure_can_open(sibling: ParaId, direction: ChannelDirection) -> Result<(), Error<T>> {
+        if sibling == T::SelfParaId::get() {
+            return Err(Error::<T>::InvalidSibling);
+        }
+        match Self::channel_state(sibling, direction) {
+            None | Some(ChannelState::CloseRequested) => Ok(()),
+            Some(_) => Err(Error::<T>::ChannelAlreadyRequested),
+        }
+    }
+
+    /// Message paying for the `Transact` of the call from the sovereign account of this
+    /// parachain on the relay chain
+    pub fn hrmp_call_message(call: HrmpCall) -> Xcm<()> {
+        let mut encoded_call = vec![T::HrmpPalletIndex::get()];
+        call.encode_to(&mut encoded_call);
+        let fee: MultiAsset = (Here, T::RelayFee::get()).into();
+        Xcm(vec![
+            WithdrawAsset(fee.clone().into()),
+            BuyExecution { fees: fee, weight_limit: Unlimited },
+            Transact {
+                origin_kind: OriginKind::Native,
+                require_weight_at_most: T::TransactWeight::get(),
+                call: encoded_call.into(),
+            },
+            RefundSurplus,
+            DepositAsset {
+                assets: Wild(AllCounted(1)),
+                beneficiary: Parachain(T::SelfParaId::get().into()).into(),
+            },
+        ])
+    }
+
+    fn send_hrmp_call(call: HrmpCall) -> Result<(), Error<T>> {
+        send_xcm::<T::XcmSender>(MultiLocation::parent(), Self::hrmp_call_message(call)).map_err(
+            |e| {
+                log::error!(target: "xcm::HrmpManager", "failed to send hrmp call: {:?}", e);
+                Error::<T>::SendFailure
+            },
+        )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,164 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use crate as hrmp_manager;
+use cumulus_primitives_core::{ChannelStatus, GetChannelInfo, ParaId};
+use frame_support::{
+    parameter_types,
+    traits::{ConstU32, Everything},
+    weights::Weight,
+};
+use frame_system as system;
+use sp_core::H256;
+use sp_runtime::{
+    testing::Header,
+    traits::{BlakeTwo256, IdentityLookup},
+};
+use xcm::latest::prelude::*;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub type AccountId = u64;
+
+pub const SELF_PARA_ID: u32 = 2000;
+pub const SIBLING: u32 = 2001;
+pub const HRMP_PALLET_INDEX: u8 = 60;
+pub const RELAY_FEE: u128 = 1_000_000_000;
+
+frame_support::construct_runtime!(
+    pub enum Test where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+        HrmpManager: hrmp_manager::{Pallet, Call, Storage, Event<T>},
+    }
+);
+
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+    pub const SS58Prefix: u8 = 42;
+    pub const SelfParaId: ParaId = ParaId::new(SELF_PARA_ID);
+    pub const HrmpPalletIndex: u8 = HRMP_PALLET_INDEX;
+    pub const RelayFee: u128 = RELAY_FEE;
+    pub const TransactWeight: Weight = Weight::from_ref_time(1_000_000_000);
+    pub static SentXcm: Vec<(MultiLocation, Xcm<()>)> = vec![];
+    pub static FailSend: bool = false;
+    pub static OpenChannels: Vec<ParaId> = vec![];
+}
+
+impl system::Config for Test {
+    type BaseCallFilter = Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Index = u64;
+    type BlockNumber = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = BlockHashCount;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = ();
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = SS58Prefix;
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+}
+
+/// Records the sent messages in `SentXcm`, fails while `FailSend` is set
+pub struct TestXcmSender;
+impl SendXcm for TestXcmSender {
+    type Ticket = (MultiLocation, Xcm<()>);
+
+    fn validate(
+        dest: &mut Option<MultiLocation>,
+        msg: &mut Option<Xcm<()>>,
+    ) -> SendResult<Self::Ticket> {
+        if FailSend::get() {
+            return Err(SendError::Transport("fail"));
+        }
+        let ticket = (dest.take().ok_or(SendError::MissingArgument)?, msg.take().unwrap());
+        Ok((ticket, MultiAssets::new()))
+    }
+
+    fn deliver(ticket: Self::Ticket) -> Result<XcmHash, SendError> {
+        let mut sent = SentXcm::get();
+        sent.push(ticket);
+        SentXcm::set(sent);
+        Ok([0; 32])
+    }
+}
+
+/// Outbound channels to the paras in `OpenChannels` are open
+pub struct TestChannelInfo;
+impl GetChannelInfo for TestChannelInfo {
+    fn get_channel_status(id: ParaId) -> ChannelStatus {
+        if OpenChannels::get().contains(&id) {
+            ChannelStatus::Ready(10, 10)
+        } else {
+            ChannelStatus::Closed
+        }
+    }
+
+    fn get_channel_max(id: ParaId) -> Option<usize> {
+        OpenChannels::get().contains(&id).then_some(10)
+    }
+}
+
+impl hrmp_manager::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
+    type ManagerOrigin = frame_system::EnsureRoot<AccountId>;
+    type SelfParaId = SelfParaId;
+    type XcmSender = TestXcmSender;
+    type ChannelInfo = TestChannelInfo;
+    type HrmpPalletIndex = HrmpPalletIndex;
+    type RelayFee = RelayFee;
+    type TransactWeight = TransactWeight;
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let t = system::GenesisConfig::default().build_storage::<Test>().unwrap();
+    let mut ext: sp_io::TestExternalities = t.into();
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
@@ -0,0 +1,203 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use crate::{mock::*, ChannelDirection, ChannelInfo, ChannelState, Error, Event, HrmpCall};
+use codec::Encode;
+use cumulus_primitives_core::ParaId;
+use frame_support::{assert_noop, assert_ok, traits::Get};
+use polkadot_parachain::primitives::HrmpChannelId;
+use sp_runtime::DispatchError;
+use xcm::latest::prelude::*;
+
+fn sibling() -> ParaId {
+    ParaId::new(SIBLING)
+}
+
+/// Checks that the call is the only `Transact` sent to the relay chain
+fn assert_sent_hrmp_call(call: HrmpCall) {
+    let mut encoded_call = vec![HRMP_PALLET_INDEX];
+    call.encode_to(&mut encoded_call);
+    let fee: MultiAsset = (Here, RELAY_FEE).into();
+    let message = Xcm(vec![
+        WithdrawAsset(fee.clone().into()),
+        BuyExecution { fees: fee, weight_limit: Unlimited },
+        Transact {
+            origin_kind: OriginKind::Native,
+            require_weight_at_most: TransactWeight::get(),
+            call: encoded_call.into(),
+        },
+        RefundSurplus,
+        DepositAsset { assets: Wild(AllCounted(1)), beneficiary: Parachain(SELF_PARA_ID).into() },
+    ]);
+    assert_eq!(SentXcm::get(), vec![(MultiLocation::parent(), message)]);
+}
+
+#[test]
+fn it_works_request_open_channel() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(HrmpManager::request_open_channel(RuntimeOrigin::root(), sibling(), 8, 1024));
+        assert_sent_hrmp_call(HrmpCall::InitOpenChannel(sibling(), 8, 1024));
+        assert_eq!(
+            HrmpManager::channel_state(sibling(), ChannelDirection::Outbound),
+            Some(ChannelState::OpenRequested)
+        );
+        System::assert_last_event(Event::<Test>::ChannelOpenRequested(sibling(), 8, 1024).into());
+
+        assert_noop!(
+            HrmpManager::request_open_channel(RuntimeOrigin::root(), sibling(), 8, 1024),
+            Error::<Test>::ChannelAlreadyRequested
+        );
+    });
+}
+
+#[test]
+fn it_works_accept_open_channel() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(HrmpManager::accept_open_channel(RuntimeOrigin::root(), sibling()));
+        assert_sent_hrmp_call(HrmpCall::AcceptOpenChannel(sibling()));
+        assert_eq!(
+            HrmpManager::channel_state(sibling(), ChannelDirection::Inbound),
+            Some(ChannelState::Accepted)
+        );
+        System::assert_last_event(Event::<Test>::ChannelAccepted(sibling()).into());
+    });
+}
+
+#[test]
+fn it_works_close_channel() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            HrmpManager::close_channel(RuntimeOrigin::root(), sibling(), ChannelDirection::Inbound),
+            Error::<Test>::ChannelNotFound
+        );
+        assert_ok!(HrmpManager::accept_open_channel(RuntimeOrigin::root(), sibling()));
+        SentXcm::set(vec![]);
+
+        assert_ok!(HrmpManager::close_channel(
+            RuntimeOrigin::root(),
+            sibling(),
+            ChannelDirection::Inbound
+        ));
+        assert_sent_hrmp_call(HrmpCall::CloseChannel(HrmpChannelId {
+            sender: sibling(),
+            recipient: ParaId::new(SELF_PARA_ID),
+        }));
+        assert_eq!(
+            HrmpManager::channel_state(sibling(), ChannelDirection::Inbound),
+            Some(ChannelState::CloseRequested)
+        );
+        System::assert_last_event(
+            Event::<Test>::ChannelCloseRequested(sibling(), ChannelDirection::Inbound).into(),
+        );
+
+        assert_noop!(
+            HrmpManager::close_channel(RuntimeOrigin::root(), sibling(), ChannelDirection::Inbound),
+            Error::<Test>::ChannelNotFound
+        );
+        assert_ok!(HrmpManager::accept_open_channel(RuntimeOrigin::root(), sibling()));
+    });
+}
+
+#[test]
+fn channel_with_self_is_rejected() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            HrmpManager::request_open_channel(
+                RuntimeOrigin::root(),
+                ParaId::new(SELF_PARA_ID),
+                8,
+                1024
+            ),
+            Error::<Test>::InvalidSibling
+        );
+    });
+}
+
+#[test]
+fn send_failure_keeps_state() {
+    new_test_ext().execute_with(|| {
+        FailSend::set(true);
+        assert_noop!(
+            HrmpManager::request_open_channel(RuntimeOrigin::root(), sibling(), 8, 1024),
+            Error::<Test>::SendFailure
+        );
+        assert_eq!(HrmpManager::channel_state(sibling(), ChannelDirection::Outbound), None);
+    });
+}
+
+#[test]
+fn only_manager_can_manage_channels() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            HrmpManager::request_open_channel(RuntimeOrigin::signed(1), sibling(), 8, 1024),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            HrmpManager::accept_open_channel(RuntimeOrigin::signed(1), sibling()),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            HrmpManager::close_channel(
+                RuntimeOrigin::signed(1),
+                sibling(),
+                ChannelDirection::Outbound
+            ),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn channels_report_relay_status() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(HrmpManager::request_open_channel(RuntimeOrigin::root(), sibling(), 8, 1024));
+        assert_ok!(HrmpManager::accept_open_channel(RuntimeOrigin::root(), sibling()));
+        let outbound = ChannelInfo {
+            sibling: sibling(),
+            direction: ChannelDirection::Outbound,
+            state: ChannelState::OpenRequested,
+            is_open: Some(false),
+        };
+        let inbound = ChannelInfo {
+            sibling: sibling(),
+            direction: ChannelDirection::Inbound,
+            state: ChannelState::Accepted,
+            is_open: None,
+        };
+        let mut channels = HrmpManager::channels();
+        channels.sort_by_key(|c| c.direction == ChannelDirection::Inbound);
+        assert_eq!(channels, vec![outbound.clone(), inbound.clone()]);
+
+        OpenChannels::set(vec![sibling()]);
+        let mut channels = HrmpManager::channels();
+        channels.sort_by_key(|c| c.direction == ChannelDirection::Inbound);
+        assert_eq!(channels, vec![ChannelInfo { is_open: Some(true), ..outbound }, inbound]);
+    });
+}
@@ -0,0 +1,74 @@
+
+//! Weights for `hrmp_manager`
+//!
+//! NOT GENERATED: the values are estimated by hand from the storage accesses of the calls
+//! and have not been measured. Regenerate them on the reference hardware with the command
+//! below before relying on them.
+
+// Command to generate:
+// ./target/release/parachain-collator
+// benchmark
+// pallet
+// --chain
+// dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --pallet
+// hrmp_manager
+// --extrinsic
+// *
+// --steps
+// 50
+// --repeat
+// 20
+// --output
+// pallets/hrmp-manager/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+use parachain_common::primitives::EXTRINSIC_FIXED_WEIGHT;
+
+/// Weight functions for `hrmp_manager`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
+	// Storage: HrmpManager Channels (r:1 w:1)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	fn request_open_channel() -> Weight {
+		Weight::from_ref_time(31_524_000)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: HrmpManager Channels (r:1 w:1)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	fn accept_open_channel() -> Weight {
+		Weight::from_ref_time(30_871_000)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: HrmpManager Channels (r:1 w:1)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	fn close_channel() -> Weight {
+		Weight::from_ref_time(31_208_000)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+}
+
+impl crate::WeightInfo for () {
+	fn request_open_channel() -> Weight {
+		EXTRINSIC_FIXED_WEIGHT
+	}
+	fn accept_open_channel() -> Weight {
+		EXTRINSIC_FIXED_WEIGHT
+	}
+	fn close_channel() -> Weight {
+		EXTRINSIC_FIXED_WEIGHT
+	}
+}
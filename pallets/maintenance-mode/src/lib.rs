@@ -0,0 +1,226 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Maintenance mode restricts the runtime to a safe set of calls, queues incoming DMP
+//! messages without executing them and suspends XCMP queue processing, so that runtime
+//! upgrades and incident response can proceed without users interacting with broken state.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+mod types;
+
+pub mod weights;
+
+pub use pallet::*;
+pub use types::*;
+
+use cumulus_primitives_core::{relay_chain::BlockNumber as RelayBlockNumber, DmpMessageHandler};
+use frame_support::{
+    dispatch::DispatchResult,
+    traits::{Contains, OffchainWorker, OnFinalize, OnIdle, OnInitialize, OnRuntimeUpgrade},
+    weights::Weight,
+};
+use sp_std::vec::Vec;
+
+pub trait WeightInfo {
+    fn enter_maintenance_mode() -> Weight;
+
+    fn resume_normal_operation() -> Weight;
+}
+
+/// Suspends and resumes execution of the incoming XCMP messages
+pub trait PauseXcmExecution {
+    fn suspend_xcm_execution() -> DispatchResult;
+
+    fn resume_xcm_execution() -> DispatchResult;
+}
+
+impl PauseXcmExecution for () {
+    fn suspend_xcm_execution() -> DispatchResult {
+        Ok(())
+    }
+
+    fn resume_xcm_execution() -> DispatchResult {
+        Ok(())
+    }
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::pallet_prelude::*;
+    use frame_system::pallet_prelude::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        type WeightInfo: WeightInfo;
+
+        /// Calls allowed in normal operation
+        type NormalCallFilter: Contains<<Self as frame_system::Config>::RuntimeCall>;
+
+        /// Calls allowed in maintenance mode
+        type MaintenanceCallFilter: Contains<<Self as frame_system::Config>::RuntimeCall>;
+
+        /// Origin allowed to enter and leave maintenance mode
+        type MaintenanceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Suspends XCMP queue processing while in maintenance mode
+        type XcmExecutionManager: PauseXcmExecution;
+
+        /// Handler of the downward messages in normal operation
+        type NormalDmpHandler: DmpMessageHandler;
+
+        /// Handler of the downward messages in maintenance mode, expected to queue them
+        /// without execution
+        type MaintenanceDmpHandler: DmpMessageHandler;
+
+        /// Hooks of the pallets executed in normal operation
+        type NormalExecutiveHooks: OnRuntimeUpgrade
+            + OnInitialize<Self::BlockNumber>
+            + OnIdle<Self::BlockNumber>
+            + OnFinalize<Self::BlockNumber>
+            + OffchainWorker<Self::BlockNumber>;
+
+        /// Hooks of the pallets executed in maintenance mode
+        type MaintenanceExecutiveHooks: OnRuntimeUpgrade
+            + OnInitialize<Self::BlockNumber>
+            + OnIdle<Self::BlockNumber>
+            + OnFinalize<Self::BlockNumber>
+            + OffchainWorker<Self::BlockNumber>;
+    }
+
+    #[pallet::pallet]
+    #[pallet::generate_store(pub(super) trait Store)]
+    pub struct Pallet<T>(_);
+
+    /// Whether the runtime is in maintenance mode
+    #[pallet::storage]
+    #[pallet::getter(fn maintenance_mode)]
+    pub type MaintenanceMode<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+    #[pallet::genesis_config]
+    #[derive(Default)]
+    pub struct GenesisConfig {
+        pub start_in_maintenance_mode: bool,
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> GenesisBuild<T> for GenesisConfig {
+        fn build(&self) {
+            MaintenanceMode::<T>::put(self.start_in_maintenance_mode);
+        }
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Runtime has entered maintenance mode
+        EnteredMaintenanceMode,
+        /// Runtime has resumed normal operation
+        NormalOperationResumed,
+        /// XCMP queue processing could not be suspended
+        /// [Error]
+        FailedToSuspendXcmExecution(DispatchError),
+        /// XCMP queue processing could not be resumed
+        /// [Error]
+        FailedToResumeXcmExecution(DispatchError),
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Runtime is already in maintenance mode
+        AlreadyInMaintenanceMode,
+        /// Runtime is not in maintenance mode
+        NotInMaintenanceMode,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Restrict the runtime to the maintenance call set and pause XCM processing
+        #[pallet::call_index(0)]
+        #[pallet::weight(<T as Config>::WeightInfo::enter_maintenance_mode())]
+        pub fn enter_maintenance_mode(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+            T::MaintenanceOrigin::ensure_origin(origin)?;
+            ensure!(!Self::maintenance_mode(), Error::<T>::AlreadyInMaintenanceMode);
+            MaintenanceMode::<T>::put(true);
+            if let Err(error) = T::XcmExecutionManager::suspend_xcm_execution() {
+                Self::deposit_event(Event::<T>::FailedToSuspendXcmExecution(error));
+            }
+            Self::deposit_event(Event::<T>::EnteredMaintenanceMode);
+            Ok(().into())
+        }
+
+        /// Return to normal operation and resume XCM processing
+        #[pallet::call_index(1)]
+        #[pallet::weight(<T as Config>::WeightInfo::resume_normal_operation())]
+        pub fn resume_normal_operation(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+            T::MaintenanceOrigin::ensure_origin(origin)?;
+            ensure!(Self::maintenance_mode(), Error::<T>::NotInMaintenanceMode);
+            MaintenanceMode::<T>::put(false);
+            if let Err(error) = T::XcmExecutionManager::resume_xcm_execution() {
+                Self::deposit_event(Event::<T>::FailedToResumeXcmExecution(error));
+            }
+            Self::deposit_event(Event::<T>::NormalOperationResumed);
+            Ok(().into())
+        }
+    }
+
+    impl<T: Config> Contains<<T as frame_system::Config>::RuntimeCall> for Pallet<T> {
+        fn contains(call: &<T as frame_system::Config>::RuntimeCall) -> bool {
+            if Self::maintenance_mode() {
+                T::MaintenanceCallFilter::contains(call)
+            } else {
+                T::NormalCallFilter::contains(call)
+            }
+        }
+    }
+
+    impl<T: Config> DmpMessageHandler for Pallet<T> {
+        fn handle_dmp_messages(
+            iter: impl Iterator<Item = (RelayBlockNumber, Vec<u8>)>,
+            limit: Weight,
+        ) -> Weight {
+            if Self::maintenance_mode() {
+                T::MaintenanceDmpHandler::handle_dmp_messages(iter, limit)
+            } else {
+                T::NormalDmpHandler::handle_dmp_messages(iter, limit)
+            }
+        }
+    }
+}
@@ -0,0 +1,185 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use crate as maintenance_mode;
+use cumulus_primitives_core::{relay_chain::BlockNumber as RelayBlockNumber, DmpMessageHandler};
+use frame_support::{
+    parameter_types,
+    traits::{Contains, Everything, Get, OnIdle, OnInitialize},
+    weights::Weight,
+};
+use frame_system as system;
+use sp_core::H256;
+use sp_runtime::{
+    testing::Header,
+    traits::{BlakeTwo256, IdentityLookup},
+    DispatchError,
+};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+type AccountId = u64;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+    pub enum Test where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+        MaintenanceMode: maintenance_mode::{Pallet, Call, Config, Storage, Event<T>},
+    }
+);
+
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+    pub const SS58Prefix: u8 = 42;
+    pub static XcmSuspended: bool = false;
+    pub static FailXcmExecutionManager: bool = false;
+    pub static LastDmpHandler: Option<&'static str> = None;
+    pub static LastHooks: Option<&'static str> = None;
+}
+
+impl system::Config for Test {
+    type BaseCallFilter = MaintenanceMode;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Index = u64;
+    type BlockNumber = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = BlockHashCount;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = ();
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = SS58Prefix;
+    type OnSetCode = ();
+    type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+impl maintenance_mode::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
+    type NormalCallFilter = Everything;
+    type MaintenanceCallFilter = MaintenanceCallFilter;
+    type MaintenanceOrigin = frame_system::EnsureRoot<AccountId>;
+    type XcmExecutionManager = TestXcmExecutionManager;
+    type NormalDmpHandler = TestDmpHandler<NormalHooksName>;
+    type MaintenanceDmpHandler = TestDmpHandler<MaintenanceHooksName>;
+    type NormalExecutiveHooks = TestHooks<NormalHooksName>;
+    type MaintenanceExecutiveHooks = TestHooks<MaintenanceHooksName>;
+}
+
+/// Only `MaintenanceMode` calls are allowed in maintenance mode
+pub struct MaintenanceCallFilter;
+impl Contains<RuntimeCall> for MaintenanceCallFilter {
+    fn contains(call: &RuntimeCall) -> bool {
+        matches!(call, RuntimeCall::MaintenanceMode(..))
+    }
+}
+
+pub struct TestXcmExecutionManager;
+impl crate::PauseXcmExecution for TestXcmExecutionManager {
+    fn suspend_xcm_execution() -> sp_runtime::DispatchResult {
+        if FailXcmExecutionManager::get() {
+            return Err(DispatchError::Other("suspend"));
+        }
+        XcmSuspended::set(true);
+        Ok(())
+    }
+
+    fn resume_xcm_execution() -> sp_runtime::DispatchResult {
+        if FailXcmExecutionManager::get() {
+            return Err(DispatchError::Other("resume"));
+        }
+        XcmSuspended::set(false);
+        Ok(())
+    }
+}
+
+/// Records the name `N` of the handler which received the messages last
+pub struct TestDmpHandler<N>(sp_std::marker::PhantomData<N>);
+impl<N: Get<&'static str>> DmpMessageHandler for TestDmpHandler<N> {
+    fn handle_dmp_messages(
+        _iter: impl Iterator<Item = (RelayBlockNumber, Vec<u8>)>,
+        _limit: Weight,
+    ) -> Weight {
+        LastDmpHandler::set(Some(N::get()));
+        Weight::zero()
+    }
+}
+
+parameter_types! {
+    pub const NormalHooksName: &'static str = "normal";
+    pub const MaintenanceHooksName: &'static str = "maintenance";
+}
+
+/// Records the name `N` of the hooks which were called last
+pub struct TestHooks<N>(sp_std::marker::PhantomData<N>);
+
+impl<N: Get<&'static str>> OnInitialize<u64> for TestHooks<N> {
+    fn on_initialize(_n: u64) -> Weight {
+        LastHooks::set(Some(N::get()));
+        Weight::zero()
+    }
+}
+
+impl<N: Get<&'static str>> OnIdle<u64> for TestHooks<N> {
+    fn on_idle(_n: u64, _remaining_weight: Weight) -> Weight {
+        LastHooks::set(Some(N::get()));
+        Weight::zero()
+    }
+}
+
+impl<N> frame_support::traits::OnFinalize<u64> for TestHooks<N> {}
+
+impl<N> frame_support::traits::OffchainWorker<u64> for TestHooks<N> {}
+
+impl<N> frame_support::traits::OnRuntimeUpgrade for TestHooks<N> {}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut ext: sp_io::TestExternalities =
+        system::GenesisConfig::default().build_storage::<Test>().unwrap().into();
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
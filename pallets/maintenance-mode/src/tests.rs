@@ -0,0 +1,129 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use crate::{mock::*, Error, Event, ExecutiveHooks};
+use cumulus_primitives_core::DmpMessageHandler;
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{Contains, OnIdle, OnInitialize},
+    weights::Weight,
+};
+use sp_runtime::{traits::Dispatchable, DispatchError};
+
+fn remark_call() -> RuntimeCall {
+    RuntimeCall::System(frame_system::Call::remark { remark: vec![] })
+}
+
+#[test]
+fn it_works_enter_and_resume() {
+    new_test_ext().execute_with(|| {
+        assert!(!MaintenanceMode::maintenance_mode());
+        assert_ok!(MaintenanceMode::enter_maintenance_mode(RuntimeOrigin::root()));
+        assert!(MaintenanceMode::maintenance_mode());
+        assert!(XcmSuspended::get());
+        System::assert_last_event(Event::<Test>::EnteredMaintenanceMode.into());
+
+        assert_ok!(MaintenanceMode::resume_normal_operation(RuntimeOrigin::root()));
+        assert!(!MaintenanceMode::maintenance_mode());
+        assert!(!XcmSuspended::get());
+        System::assert_last_event(Event::<Test>::NormalOperationResumed.into());
+    });
+}
+
+#[test]
+fn it_fails_enter_twice_or_resume_in_normal_mode() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            MaintenanceMode::resume_normal_operation(RuntimeOrigin::root()),
+            Error::<Test>::NotInMaintenanceMode
+        );
+        assert_ok!(MaintenanceMode::enter_maintenance_mode(RuntimeOrigin::root()));
+        assert_noop!(
+            MaintenanceMode::enter_maintenance_mode(RuntimeOrigin::root()),
+            Error::<Test>::AlreadyInMaintenanceMode
+        );
+    });
+}
+
+#[test]
+fn it_fails_enter_not_root() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            MaintenanceMode::enter_maintenance_mode(RuntimeOrigin::signed(1)),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn it_works_xcm_manager_failure_is_reported() {
+    new_test_ext().execute_with(|| {
+        FailXcmExecutionManager::set(true);
+        assert_ok!(MaintenanceMode::enter_maintenance_mode(RuntimeOrigin::root()));
+        assert!(MaintenanceMode::maintenance_mode());
+        System::assert_has_event(
+            Event::<Test>::FailedToSuspendXcmExecution(DispatchError::Other("suspend")).into(),
+        );
+        FailXcmExecutionManager::set(false);
+    });
+}
+
+#[test]
+fn it_works_call_filter_follows_mode() {
+    new_test_ext().execute_with(|| {
+        assert!(<MaintenanceMode as Contains<RuntimeCall>>::contains(&remark_call()));
+        assert_ok!(MaintenanceMode::enter_maintenance_mode(RuntimeOrigin::root()));
+        assert!(!<MaintenanceMode as Contains<RuntimeCall>>::contains(&remark_call()));
+
+        assert_noop!(
+            remark_call().dispatch(RuntimeOrigin::signed(1)),
+            frame_system::Error::<Test>::CallFiltered
+        );
+        assert_ok!(RuntimeCall::MaintenanceMode(crate::Call::resume_normal_operation {})
+            .dispatch(RuntimeOrigin::root()));
+        assert_ok!(remark_call().dispatch(RuntimeOrigin::signed(1)));
+    });
+}
+
+#[test]
+fn it_works_dmp_handler_and_hooks_follow_mode() {
+    new_test_ext().execute_with(|| {
+        MaintenanceMode::handle_dmp_messages(vec![(1, vec![1])].into_iter(), Weight::zero());
+        assert_eq!(LastDmpHandler::get(), Some("normal"));
+        ExecutiveHooks::<Test>::on_initialize(1);
+        assert_eq!(LastHooks::get(), Some("normal"));
+
+        assert_ok!(MaintenanceMode::enter_maintenance_mode(RuntimeOrigin::root()));
+        MaintenanceMode::handle_dmp_messages(vec![(1, vec![1])].into_iter(), Weight::zero());
+        assert_eq!(LastDmpHandler::get(), Some("maintenance"));
+        ExecutiveHooks::<Test>::on_idle(1, Weight::zero());
+        assert_eq!(LastHooks::get(), Some("maintenance"));
+    });
+}
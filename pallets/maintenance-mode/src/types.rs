@@ -0,0 +1,122 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use crate::*;
+use sp_std::marker::PhantomData;
+
+/// Pallet hooks to be used by `frame_executive::Executive`, switches between
+/// `Config::NormalExecutiveHooks` and `Config::MaintenanceExecutiveHooks`
+pub struct ExecutiveHooks<T>(PhantomData<T>);
+
+impl<T: Config> OnIdle<T::BlockNumber> for ExecutiveHooks<T> {
+    fn on_idle(n: T::BlockNumber, remaining_weight: Weight) -> Weight {
+        if Pallet::<T>::maintenance_mode() {
+            T::MaintenanceExecutiveHooks::on_idle(n, remaining_weight)
+        } else {
+            T::NormalExecutiveHooks::on_idle(n, remaining_weight)
+        }
+    }
+}
+
+impl<T: Config> OnInitialize<T::BlockNumber> for ExecutiveHooks<T> {
+    fn on_initialize(n: T::BlockNumber) -> Weight {
+        if Pallet::<T>::maintenance_mode() {
+            T::MaintenanceExecutiveHooks::on_initialize(n)
+        } else {
+            T::NormalExecutiveHooks::on_initialize(n)
+        }
+    }
+}
+
+impl<T: Config> OnFinalize<T::BlockNumber> for ExecutiveHooks<T> {
+    fn on_finalize(n: T::BlockNumber) {
+        if Pallet::<T>::maintenance_mode() {
+            T::MaintenanceExecutiveHooks::on_finalize(n)
+        } else {
+            T::NormalExecutiveHooks::on_finalize(n)
+        }
+    }
+}
+
+impl<T: Config> OffchainWorker<T::BlockNumber> for ExecutiveHooks<T> {
+    fn offchain_worker(n: T::BlockNumber) {
+        if Pallet::<T>::maintenance_mode() {
+            T::MaintenanceExecutiveHooks::offchain_worker(n)
+        } else {
+            T::NormalExecutiveHooks::offchain_worker(n)
+        }
+    }
+}
+
+impl<T: Config> OnRuntimeUpgrade for ExecutiveHooks<T> {
+    fn on_runtime_upgrade() -> Weight {
+        if Pallet::<T>::maintenance_mode() {
+            T::MaintenanceExecutiveHooks::on_runtime_upgrade()
+        } else {
+            T::NormalExecutiveHooks::on_runtime_upgrade()
+        }
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+        if Pallet::<T>::maintenance_mode() {
+            T::MaintenanceExecutiveHooks::pre_upgrade()
+        } else {
+            T::NormalExecutiveHooks::pre_upgrade()
+        }
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+        if Pallet::<T>::maintenance_mode() {
+            T::MaintenanceExecutiveHooks::post_upgrade(state)
+        } else {
+            T::NormalExecutiveHooks::post_upgrade(state)
+        }
+    }
+}
+
+#[cfg(feature = "try-runtime")]
+impl<T: Config> frame_support::traits::TryState<T::BlockNumber> for ExecutiveHooks<T>
+where
+    T::NormalExecutiveHooks: frame_support::traits::TryState<T::BlockNumber>,
+    T::MaintenanceExecutiveHooks: frame_support::traits::TryState<T::BlockNumber>,
+{
+    fn try_state(
+        n: T::BlockNumber,
+        targets: frame_support::traits::TryStateSelect,
+    ) -> Result<(), &'static str> {
+        if Pallet::<T>::maintenance_mode() {
+            T::MaintenanceExecutiveHooks::try_state(n, targets)
+        } else {
+            T::NormalExecutiveHooks::try_state(n, targets)
+        }
+    }
+}
@@ -0,0 +1,62 @@
+
+//! Weights for `maintenance_mode`
+//!
+//! NOT GENERATED: the values are estimated by hand from the storage accesses of the calls
+//! and have not been measured. Regenerate them on the reference hardware with the command
+//! below before relying on them.
+
+// Command to generate:
+// ./target/release/parachain-collator
+// benchmark
+// pallet
+// --chain
+// dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --pallet
+// maintenance_mode
+// --extrinsic
+// *
+// --steps
+// 50
+// --repeat
+// 20
+// --output
+// pallets/maintenance-mode/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+use parachain_common::primitives::EXTRINSIC_FIXED_WEIGHT;
+
+/// Weight functions for `maintenance_mode`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
+	// Storage: MaintenanceMode MaintenanceMode (r:1 w:1)
+	// Storage: XcmpQueue QueueSuspended (r:0 w:1)
+	fn enter_maintenance_mode() -> Weight {
+		Weight::from_ref_time(18_312_000)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: MaintenanceMode MaintenanceMode (r:1 w:1)
+	// Storage: XcmpQueue QueueSuspended (r:0 w:1)
+	fn resume_normal_operation() -> Weight {
+		Weight::from_ref_time(18_046_000)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+}
+
+impl crate::WeightInfo for () {
+	fn enter_maintenance_mode() -> Weight {
+		EXTRINSIC_FIXED_WEIGHT
+	}
+
+	fn resume_normal_operation() -> Weight {
+		EXTRINSIC_FIXED_WEIGHT
+	}
+}
@@ -0,0 +1,112 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::*;
+use bridge_types::types::AssetKind;
+use frame_benchmarking::{account, benchmarks, BenchmarkError};
+use frame_support::traits::EnsureOrigin;
+use frame_system::RawOrigin;
+use parachain_common::primitives::AssetId;
+use xcm::v3::{
+    Junction::{AccountId32 as AccountId32Junction, GeneralKey, Parachain},
+    Junctions::{X1, X2},
+    MultiLocation,
+};
+use xcm_app::Pallet as XCMApp;
+
+fn assert_last_event<T: Config>(generic_event: xcm_app::Event<T>) {
+    let event: <T as xcm_app::Config>::RuntimeEvent = generic_event.into();
+    frame_system::Pallet::<T>::assert_last_event(event.into());
+}
+
+fn test_asset_id() -> AssetId {
+    [1; 32].into()
+}
+
+fn test_multilocation() -> MultiLocation {
+    let mut key = [0u8; 32];
+    key[..10].copy_from_slice(b"TEST_ASSET");
+    MultiLocation { parents: 1, interior: X2(Parachain(666), GeneralKey { length: 10, data: key }) }
+}
+
+benchmarks! {
+    test_channel_transfer {
+        let account_id: T::AccountId = account("recipient", 0, 0);
+        let asset_id = test_asset_id();
+        let amount = 1_000_000_000_000u128;
+    }: _(RawOrigin::Root, account_id, asset_id, amount)
+
+    test_xcm_transfer {
+        let asset_id = test_asset_id();
+        XCMApp::<T>::register_mapping(asset_id, MultiLocation::parent())
+            .map_err(|e| e.error)?;
+        let sender: T::AccountId = account("sender", 0, 0);
+        let recipient = MultiLocation::new(
+            1,
+            X1(AccountId32Junction { network: None, id: [5; 32] }),
+        );
+        let amount = 1_000_000_000_000u128;
+    }: _(
+        RawOrigin::Root,
+        asset_id,
+        sender.clone(),
+        xcm::VersionedMultiLocation::V3(recipient),
+        amount
+    )
+    verify {
+        assert_last_event::<T>(xcm_app::Event::<T>::AssetTransferred(sender, recipient, asset_id, amount));
+    }
+
+    register_mapping {
+        let asset_id = test_asset_id();
+        let multilocation = test_multilocation();
+        let origin = <T as xcm_app::Config>::ManagerOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+    }: _<T::RuntimeOrigin>(origin, asset_id, multilocation)
+    verify {
+        assert_eq!(XCMApp::<T>::get_multilocation_from_asset_id(asset_id), Some(multilocation));
+        assert_eq!(XCMApp::<T>::get_asset_id_from_multilocation(multilocation), Some(asset_id));
+    }
+
+    register_asset {
+        let asset_id = test_asset_id();
+        let multilocation = test_multilocation();
+        let origin = <T as xcm_app::Config>::ManagerOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+    }: _<T::RuntimeOrigin>(
+        origin,
+        asset_id,
+        xcm::v3::AssetId::Concrete(multilocation),
+        AssetKind::Sidechain
+    )
+    verify {
+        assert_last_event::<T>(xcm_app::Event::<T>::MappingCreated(asset_id, multilocation));
+    }
+}
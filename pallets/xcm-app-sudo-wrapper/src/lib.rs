@@ -29,18 +29,38 @@
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+
 pub use pallet::*;
 
+use frame_support::weights::Weight;
+
+pub trait WeightInfo {
+    fn test_channel_transfer() -> Weight;
+
+    fn test_xcm_transfer() -> Weight;
+
+    fn register_mapping() -> Weight;
+
+    fn register_asset() -> Weight;
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
     use frame_support::{dispatch::DispatchResultWithPostInfo, pallet_prelude::*};
     use frame_system::pallet_prelude::*;
     use parachain_common::primitives::AssetId;
+    use xcm::v3::MultiLocation;
+    use xcm_app::{DelayedChange, WeightInfo as _};
 
     #[pallet::config]
     pub trait Config: frame_system::Config + xcm_app::Config {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        type WeightInfo: WeightInfo;
     }
 
     #[pallet::pallet]
@@ -60,7 +80,7 @@ pub mod pallet {
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         #[pallet::call_index(0)]
-        #[pallet::weight(0)]
+        #[pallet::weight(<T as Config>::WeightInfo::test_channel_transfer())]
         pub fn test_channel_transfer(
             origin: OriginFor<T>,
             account_id: T::AccountId,
@@ -73,7 +93,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(1)]
-        #[pallet::weight(0)]
+        #[pallet::weight(<T as Config>::WeightInfo::test_xcm_transfer())]
         pub fn test_xcm_transfer(
             origin: OriginFor<T>,
             asset_id: AssetId,
@@ -85,5 +105,71 @@ pub mod pallet {
             xcm_app::Pallet::<T>::do_xcm_asset_transfer(asset_id, sender, recipient, amount)?;
             Ok(().into())
         }
+
+        #[pallet::call_index(2)]
+        #[pallet::weight(<T as Config>::WeightInfo::register_mapping())]
+        pub fn register_mapping(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+            multilocation: MultiLocation,
+        ) -> DispatchResultWithPostInfo {
+            <T as xcm_app::Config>::ManagerOrigin::ensure_origin(origin)?;
+            xcm_app::Pallet::<T>::register_mapping(asset_id, multilocation)
+        }
+
+        /// Changes of the existing mappings are announced and applied after
+        /// `xcm_app::Config::ChangeDelay` like `xcm_app::Pallet::announce_change` does
+        #[pallet::call_index(3)]
+        #[pallet::weight(<T as xcm_app::Config>::WeightInfo::announce_change())]
+        pub fn change_asset_mapping(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+            new_multilocation: MultiLocation,
+        ) -> DispatchResultWithPostInfo {
+            xcm_app::Pallet::<T>::announce_change(
+                origin,
+                DelayedChange::ChangeAssetMapping { asset_id, new_multilocation },
+            )
+        }
+
+        #[pallet::call_index(4)]
+        #[pallet::weight(<T as xcm_app::Config>::WeightInfo::announce_change())]
+        pub fn change_multilocation_mapping(
+            origin: OriginFor<T>,
+            multilocation: MultiLocation,
+            new_asset_id: AssetId,
+        ) -> DispatchResultWithPostInfo {
+            xcm_app::Pallet::<T>::announce_change(
+                origin,
+                DelayedChange::ChangeMultilocationMapping { multilocation, new_asset_id },
+            )
+        }
+
+        #[pallet::call_index(5)]
+        #[pallet::weight(<T as xcm_app::Config>::WeightInfo::announce_change())]
+        pub fn delete_mapping(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+        ) -> DispatchResultWithPostInfo {
+            xcm_app::Pallet::<T>::announce_change(origin, DelayedChange::DeleteMapping { asset_id })
+        }
+
+        #[pallet::call_index(6)]
+        #[pallet::weight(<T as Config>::WeightInfo::register_asset())]
+        pub fn register_asset(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+            multiasset: xcm::v3::AssetId,
+            asset_kind: bridge_types::types::AssetKind,
+        ) -> DispatchResultWithPostInfo {
+            <T as xcm_app::Config>::ManagerOrigin::ensure_origin(origin)?;
+            xcm_app::Pallet::<T>::do_register_asset(
+                xcm_app::Pallet::<T>::sora_network(),
+                asset_id,
+                multiasset,
+                asset_kind,
+            )?;
+            Ok(().into())
+        }
     }
 }
@@ -0,0 +1,94 @@
+
+//! Weights for `xcm_app_sudo_wrapper`
+//!
+//! NOT GENERATED: the values are estimated by hand from the storage accesses of the calls
+//! and have not been measured. Regenerate them on the reference hardware with
+//! `./housekeeping/benchmark.sh xcm_app_sudo_wrapper` before relying on them.
+
+// Command to generate:
+// ./target/release/parachain-collator
+// benchmark
+// pallet
+// --template=./housekeeping/pallet-weight-template.hbs
+// --chain
+// dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --pallet
+// xcm_app_sudo_wrapper
+// --extrinsic
+// *
+// --steps
+// 50
+// --repeat
+// 20
+// --output
+// pallets/xcm-app-sudo-wrapper/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+use parachain_common::primitives::EXTRINSIC_FIXED_WEIGHT;
+
+/// Weight functions for `xcm_app_sudo_wrapper`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
+	// Storage: SubstrateBridgeOutboundChannel MessageQueues (r:1 w:1)
+	// Storage: SubstrateBridgeOutboundChannel ChannelNonces (r:1 w:0)
+	// Storage: SubstrateBridgeOutboundChannel Interval (r:1 w:0)
+	fn test_channel_transfer() -> Weight {
+		Weight::from_ref_time(38_207_000)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: XCMApp AssetIdToMultilocation (r:1 w:0)
+	// Storage: ParachainInfo ParachainId (r:1 w:0)
+	// Storage: PolkadotXcm SupportedVersion (r:1 w:0)
+	// Storage: PolkadotXcm VersionDiscoveryQueue (r:1 w:1)
+	// Storage: PolkadotXcm SafeXcmVersion (r:1 w:0)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	fn test_xcm_transfer() -> Weight {
+		Weight::from_ref_time(116_930_000)
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: XCMApp AssetIdToMultilocation (r:1 w:1)
+	// Storage: XCMApp MultilocationToAssetId (r:1 w:1)
+	fn register_mapping() -> Weight {
+		Weight::from_ref_time(23_981_000)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: XCMApp AssetIdToMultilocation (r:1 w:1)
+	// Storage: XCMApp MultilocationToAssetId (r:1 w:1)
+	// Storage: SubstrateBridgeOutboundChannel MessageQueues (r:1 w:1)
+	// Storage: SubstrateBridgeOutboundChannel ChannelNonces (r:1 w:0)
+	// Storage: SubstrateBridgeOutboundChannel Interval (r:1 w:0)
+	fn register_asset() -> Weight {
+		Weight::from_ref_time(57_885_000)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+}
+
+impl crate::WeightInfo for () {
+	fn test_channel_transfer() -> Weight {
+		EXTRINSIC_FIXED_WEIGHT
+	}
+
+	fn test_xcm_transfer() -> Weight {
+		EXTRINSIC_FIXED_WEIGHT
+	}
+
+	fn register_mapping() -> Weight {
+		EXTRINSIC_FIXED_WEIGHT
+	}
+
+	fn register_asset() -> Weight {
+		EXTRINSIC_FIXED_WEIGHT
+	}
+}
@@ -30,33 +30,97 @@
 
 use super::*;
 use crate::Pallet as XCMApp;
-use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
-use frame_support::WeakBoundedVec;
+use bridge_types::{types::AssetKind, SubNetworkId};
+use frame_benchmarking::{account, benchmarks, BenchmarkError};
+use frame_support::traits::EnsureOrigin;
 use frame_system::RawOrigin;
-use xcm::{
-    opaque::latest::{
-        Junction::{GeneralKey, Parachain},
-        Junctions::X2,
-    },
-    v1::MultiLocation,
+use sp_runtime::traits::{Convert, Zero};
+use xcm::v3::{
+    Junction::{AccountId32 as AccountId32Junction, GeneralKey, Parachain},
+    Junctions::{X1, X2},
+    MultiLocation, Response,
 };
 
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+    frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+fn test_asset_id() -> AssetId {
+    [1; 32].into()
+}
+
+fn test_new_asset_id() -> AssetId {
+    [2; 32].into()
+}
+
+fn test_multilocation() -> MultiLocation {
+    MultiLocation {
+        parents: 1,
+        interior: X2(Parachain(666), GeneralKey { length: 10, data: test_general_key() }),
+    }
+}
+
 benchmarks! {
+    transfer {
+        let asset_id = test_asset_id();
+        // Relay chain native asset is the only asset which is guaranteed to be transferable
+        // by `XcmTransfer` without any additional setup on the destination side.
+        XCMApp::<T>::register_mapping(asset_id, MultiLocation::parent())
+            .map_err(|e| e.error)?;
+        AllowedDestinations::<T>::insert(MultiLocation::parent(), true);
+        let sender: T::AccountId = account("sender", 0, 0);
+        let recipient = MultiLocation::new(
+            1,
+            X1(AccountId32Junction { network: None, id: [5; 32] }),
+        );
+        let amount = 1_000_000_000_000u128;
+        let origin = T::CallOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        let network_id = T::CallOrigin::ensure_origin(origin.clone())
+            .map_err(|_| BenchmarkError::Weightless)?
+            .network_id;
+        let sender_account =
+            XCMApp::<T>::sora_account_id(network_id, T::AccountIdConverter::convert(sender.clone()));
+    }: _<T::RuntimeOrigin>(
+        origin,
+        asset_id,
+        sender,
+        xcm::VersionedMultiLocation::V3(recipient),
+        amount
+    )
+    verify {
+        assert_last_event::<T>(
+            Event::<T>::AssetTransferred(sender_account, recipient, asset_id, amount).into()
+        );
+    }
+
+    register_asset {
+        let asset_id = test_asset_id();
+        let multilocation = test_multilocation();
+        let origin = T::CallOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+    }: _<T::RuntimeOrigin>(
+        origin,
+        asset_id,
+        xcm::v3::AssetId::Concrete(multilocation),
+        AssetKind::Sidechain
+    )
+    verify {
+        assert_last_event::<T>(Event::<T>::MappingCreated(asset_id, multilocation).into());
+        assert_eq!(XCMApp::<T>::get_asset_id_from_multilocation(multilocation), Some(asset_id));
+    }
+
     register_mapping {
-        let asset_id = [
-            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
-            1, 1, 1,
-        ].into();
-        let multilocation = MultiLocation {
-            parents: 1,
-            interior: X2(Parachain(666), GeneralKey(test_general_key())),
-        };
-    }: _(RawOrigin::Root, asset_id, multilocation.clone())
+        let asset_id = test_asset_id();
+        let multilocation = test_multilocation();
+    }: {
+        XCMApp::<T>::register_mapping(asset_id, multilocation).map_err(|e| e.error)?;
+    }
     verify {
         assert_eq!(
             XCMApp::<T>::get_multilocation_from_asset_id(asset_id)
                 .expect("register_mapping: multilocation is None"),
-            multilocation.clone()
+            multilocation
         );
         assert_eq!(
             XCMApp::<T>::get_asset_id_from_multilocation(multilocation)
@@ -66,84 +130,219 @@ benchmarks! {
     }
 
     change_asset_mapping {
-        let asset_id = [
-            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
-            1, 1, 1,
-        ].into();
+        let asset_id = test_asset_id();
         let multilocation = MultiLocation::parent();
-        let new_multilocation = MultiLocation {
-            parents: 1,
-            interior: X2(Parachain(666), GeneralKey(test_general_key())),
-        };
-        XCMApp::<T>::register_mapping(RawOrigin::Root.into(), asset_id, multilocation.clone())
-        .expect("change_asset_mapping: failed to create a map");
-    }: _(RawOrigin::Root, asset_id, new_multilocation.clone())
+        let new_multilocation = test_multilocation();
+        XCMApp::<T>::register_mapping(asset_id, multilocation)
+            .map_err(|e| e.error)?;
+    }: {
+        XCMApp::<T>::change_asset_mapping(asset_id, new_multilocation).map_err(|e| e.error)?;
+    }
     verify {
         assert_eq!(
             XCMApp::<T>::get_multilocation_from_asset_id(asset_id)
                 .expect("change_asset_mapping: new_multilocation is None"),
-            new_multilocation.clone()
+            new_multilocation
         );
         assert_eq!(
-            XCMApp::<T>::get_asset_id_from_multilocation(new_multilocation).expect(
-                "change_asset_mapping: asset_id is None"
-            ),
+            XCMApp::<T>::get_asset_id_from_multilocation(new_multilocation)
+                .expect("change_asset_mapping: asset_id is None"),
             asset_id
         );
         assert_eq!(XCMApp::<T>::get_asset_id_from_multilocation(multilocation), None);
     }
 
     change_multilocation_mapping {
-        let asset_id = [
-            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
-            1, 1, 1,
-        ].into();
-        let new_asset_id = [
-            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-            2, 2, 2,
-        ].into();
-        let multilocation = MultiLocation {
-            parents: 1,
-            interior: X2(Parachain(666), GeneralKey(test_general_key())),
-        };
-        XCMApp::<T>::register_mapping(RawOrigin::Root.into(), asset_id, multilocation.clone())
-        .expect("change_multilocation_mapping: failed to create a map");
-    }: _(RawOrigin::Root, multilocation.clone(), new_asset_id)
+        let asset_id = test_asset_id();
+        let new_asset_id = test_new_asset_id();
+        let multilocation = test_multilocation();
+        XCMApp::<T>::register_mapping(asset_id, multilocation)
+            .map_err(|e| e.error)?;
+    }: {
+        XCMApp::<T>::change_multilocation_mapping(multilocation, new_asset_id)
+            .map_err(|e| e.error)?;
+    }
     verify {
         assert_eq!(
             XCMApp::<T>::get_multilocation_from_asset_id(new_asset_id)
                 .expect("change_multilocation_mapping: new_multilocation is None"),
-                multilocation.clone()
+            multilocation
         );
         assert_eq!(
-            XCMApp::<T>::get_asset_id_from_multilocation(multilocation).expect(
-                "change_multilocation_mapping: asset_id is None"
-            ),
+            XCMApp::<T>::get_asset_id_from_multilocation(multilocation)
+                .expect("change_multilocation_mapping: asset_id is None"),
             new_asset_id
         );
         assert_eq!(XCMApp::<T>::get_multilocation_from_asset_id(asset_id), None);
     }
 
     delete_mapping {
-        let asset_id = [
-            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
-            1, 1, 1,
-        ].into();
-        let multilocation = MultiLocation {
-            parents: 1,
-            interior: X2(Parachain(666), GeneralKey(test_general_key())),
-        };
-        XCMApp::<T>::register_mapping(RawOrigin::Root.into(), asset_id, multilocation.clone())
-        .expect("change_multilocation_mapping: failed to create a map");
-    }: _(RawOrigin::Root, asset_id)
+        let asset_id = test_asset_id();
+        let multilocation = test_multilocation();
+        XCMApp::<T>::register_mapping(asset_id, multilocation)
+            .map_err(|e| e.error)?;
+    }: {
+        XCMApp::<T>::delete_mapping(asset_id).map_err(|e| e.error)?;
+    }
     verify {
         assert_eq!(XCMApp::<T>::get_multilocation_from_asset_id(asset_id), None);
         assert_eq!(XCMApp::<T>::get_asset_id_from_multilocation(multilocation), None);
     }
-}
 
-impl_benchmark_test_suite!(XCMApp, crate::mock::new_test_ext(), crate::mock::Test,);
+    set_relay_network {
+        let network = NetworkId::Kusama;
+        let origin = T::ManagerOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+    }: _<T::RuntimeOrigin>(origin, network)
+    verify {
+        assert_last_event::<T>(Event::<T>::RelayNetworkChanged(network).into());
+        assert_eq!(XCMApp::<T>::relay_network(), network);
+    }
+
+    set_sora_network {
+        let network = SubNetworkId::Rococo;
+        let origin = T::ManagerOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+    }: _<T::RuntimeOrigin>(origin, network)
+    verify {
+        assert_last_event::<T>(Event::<T>::SoraNetworkChanged(network).into());
+        assert_eq!(XCMApp::<T>::sora_network(), network);
+    }
+
+    announce_change {
+        let asset_id = test_asset_id();
+        XCMApp::<T>::register_mapping(asset_id, MultiLocation::parent())
+            .map_err(|e| e.error)?;
+        let change = DelayedChange::ChangeAssetMapping {
+            asset_id,
+            new_multilocation: test_multilocation(),
+        };
+        let origin = T::ManagerOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+    }: _<T::RuntimeOrigin>(origin, change.clone())
+    verify {
+        let execute_at = frame_system::Pallet::<T>::block_number() + T::ChangeDelay::get();
+        assert_last_event::<T>(Event::<T>::ChangeAnnounced(0, change, execute_at).into());
+    }
+
+    cancel_change {
+        let asset_id = test_asset_id();
+        XCMApp::<T>::register_mapping(asset_id, MultiLocation::parent())
+            .map_err(|e| e.error)?;
+        XCMApp::<T>::announce_change(
+            RawOrigin::Root.into(),
+            DelayedChange::ChangeAssetMapping { asset_id, new_multilocation: test_multilocation() },
+        ).map_err(|e| e.error)?;
+        let origin = T::CancelOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+    }: _<T::RuntimeOrigin>(origin, 0)
+    verify {
+        assert_last_event::<T>(Event::<T>::ChangeCancelled(0).into());
+        assert!(XCMApp::<T>::pending_change(0).is_none());
+    }
+
+    apply_change {
+        let asset_id = test_asset_id();
+        let new_multilocation = test_multilocation();
+        XCMApp::<T>::register_mapping(asset_id, MultiLocation::parent())
+            .map_err(|e| e.error)?;
+        XCMApp::<T>::announce_change(
+            RawOrigin::Root.into(),
+            DelayedChange::ChangeAssetMapping { asset_id, new_multilocation },
+        ).map_err(|e| e.error)?;
+    }: _(RawOrigin::Root, 0)
+    verify {
+        assert_last_event::<T>(Event::<T>::ChangeApplied(0, Ok(())).into());
+        assert_eq!(XCMApp::<T>::get_multilocation_from_asset_id(asset_id), Some(new_multilocation));
+    }
+
+    send_to_sora {
+        let asset_id = test_asset_id();
+        XCMApp::<T>::register_mapping(asset_id, MultiLocation::parent())
+            .map_err(|e| e.error)?;
+        AssetPolicies::<T>::insert(asset_id, AssetPolicy::HoldLocally);
+        let caller: T::AccountId = account("caller", 0, 0);
+        let amount: T::Balance = 1_000_000_000u32.into();
+        T::LocalAssets::deposit(asset_id, &caller, amount)?;
+    }: _(RawOrigin::Signed(caller.clone()), asset_id, amount)
+    verify {
+        assert!(T::LocalAssets::free_balance(asset_id, &caller).is_zero());
+    }
+
+    send_to_parachain {
+        let asset_id = test_asset_id();
+        XCMApp::<T>::register_mapping(asset_id, MultiLocation::parent())
+            .map_err(|e| e.error)?;
+        AssetPolicies::<T>::insert(asset_id, AssetPolicy::HoldLocally);
+        AllowedDestinations::<T>::insert(MultiLocation::parent(), true);
+        let caller: T::AccountId = account("caller", 0, 0);
+        let recipient = MultiLocation::new(
+            1,
+            X1(AccountId32Junction { network: None, id: [5; 32] }),
+        );
+        let amount: T::Balance = 1_000_000_000u32.into();
+        T::LocalAssets::deposit(asset_id, &caller, amount)?;
+    }: _(
+        RawOrigin::Signed(caller.clone()),
+        asset_id,
+        xcm::VersionedMultiLocation::V3(recipient),
+        amount
+    )
+    verify {
+        assert_last_event::<T>(Event::<T>::AssetTransferred(
+            caller,
+            recipient,
+            asset_id,
+            T::BalanceConverter::convert(amount),
+        ).into());
+    }
+
+    transact {
+        let asset_id = test_asset_id();
+        let destination = MultiLocation::new(1, X1(Parachain(2001)));
+        XCMApp::<T>::register_mapping(asset_id, destination)
+            .map_err(|e| e.error)?;
+        let sender: T::AccountId = account("sender", 0, 0);
+        let call = vec![0; T::MaxTransactCallLength::get() as usize];
+        let origin = T::CallOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        let network_id = T::CallOrigin::ensure_origin(origin.clone())
+            .map_err(|_| BenchmarkError::Weightless)?
+            .network_id;
+        let sender_account =
+            XCMApp::<T>::sora_account_id(network_id, T::AccountIdConverter::convert(sender.clone()));
+    }: _<T::RuntimeOrigin>(
+        origin,
+        sender,
+        xcm::VersionedMultiLocation::V3(destination),
+        asset_id,
+        1_000_000_000_000u128,
+        T::MaxTransactWeight::get(),
+        call
+    )
+    verify {
+        assert_eq!(XCMApp::<T>::pending_transact(0), Some((sender_account, destination)));
+    }
+
+    report_transact {
+        let origin = T::ResponseOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        let responder = T::ResponseOrigin::ensure_origin(origin.clone())
+            .map_err(|_| BenchmarkError::Weightless)?;
+        let sender: T::AccountId = account("sender", 0, 0);
+        PendingTransacts::<T>::insert(0, (sender, responder));
+        let error = Some((0, xcm::v3::Error::FailedToDecode));
+    }: _<T::RuntimeOrigin>(origin, 0, Response::ExecutionResult(error))
+    verify {
+        assert_last_event::<T>(Event::<T>::TransactReported(0, error).into());
+        assert!(XCMApp::<T>::pending_transact(0).is_none());
+    }
+
+    impl_benchmark_test_suite!(XCMApp, crate::mock::new_test_ext(), crate::mock::Test);
+}
 
-pub fn test_general_key() -> WeakBoundedVec<u8, frame_support::traits::ConstU32<32>> {
-    WeakBoundedVec::try_from(b"TEST_ASSET".to_vec()).unwrap()
+pub fn test_general_key() -> [u8; 32] {
+    let mut key = [0u8; 32];
+    key[..10].copy_from_slice(b"TEST_ASSET");
+    key
 }
@@ -29,68 +29,131 @@
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use crate::*;
-use frame_support::fail;
-use sp_runtime::traits::Convert;
+use frame_support::{fail, traits::Contains};
+use sp_runtime::traits::{BlakeTwo256, Convert, Hash, TrailingZeroInput};
+use sp_std::marker::PhantomData;
+
+/// Prefix of the hashed data the parachain accounts of the SORA accounts are derived from
+const SORA_ACCOUNT_PREFIX: &[u8] = b"xcm_app/sora_account";
+
+impl<T: Config> Pallet<T> {
+    /// Whether the balances of the asset are kept in `Config::LocalAssets`
+    pub fn is_held_locally(asset_id: AssetId) -> bool {
+        Self::asset_policy(asset_id) == AssetPolicy::HoldLocally
+    }
+
+    /// Parachain account of the SORA account, derived from the hash of the SORA network and
+    /// the account so that every SORA account has its own account on this parachain
+    pub fn sora_account_id(network_id: SubNetworkId, account: AccountId32) -> T::AccountId {
+        let hash = BlakeTwo256::hash_of(&(SORA_ACCOUNT_PREFIX, network_id, account));
+        T::AccountId::decode(&mut TrailingZeroInput::new(hash.as_ref()))
+            .expect("infinite length input; no invalid inputs for type; qed")
+    }
+
+    /// Derive the parachain account of the SORA account and remember the SORA account it
+    /// belongs to, so that the assets deposited to the derived account reach the SORA account
+    pub fn register_sora_account(network_id: SubNetworkId, account: AccountId32) -> T::AccountId {
+        let account_id = Self::sora_account_id(network_id, account.clone());
+        SoraAccounts::<T>::insert(&account_id, (network_id, account));
+        account_id
+    }
+
+    /// SORA network and account the assets of the parachain account are sent to, the accounts
+    /// which are not derived from a SORA account are sent to the same account on `SoraNetwork`
+    pub fn sora_recipient(account_id: &T::AccountId) -> (SubNetworkId, AccountId32) {
+        Self::sora_account(account_id).unwrap_or_else(|| {
+            (Self::sora_network(), T::AccountIdConverter::convert(account_id.clone()))
+        })
+    }
+}
+
+/// Recipients accepted by `Pallet::ensure_valid_recipient_location`, filters the destinations
+/// of `XcmTransfer` so that the assets are not sent around the checks of this pallet
+pub struct AllowedRecipients<T>(PhantomData<T>);
+
+impl<T: Config> Contains<MultiLocation> for AllowedRecipients<T> {
+    fn contains(location: &MultiLocation) -> bool {
+        Pallet::<T>::ensure_valid_recipient_location(location).is_ok()
+    }
+}
 
 // IMPLS
 impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
     type CurrencyId = AssetId;
     type Balance = T::Balance;
 
-    fn minimum_balance(_currency_id: Self::CurrencyId) -> Self::Balance {
+    fn minimum_balance(currency_id: Self::CurrencyId) -> Self::Balance {
         log::trace!(
             target: "xcm::XCMApp",
             "minimum_balance",
         );
+        if Self::is_held_locally(currency_id) {
+            return T::LocalAssets::minimum_balance(currency_id);
+        }
         Default::default()
     }
 
-    fn total_issuance(_currency_id: Self::CurrencyId) -> Self::Balance {
+    fn total_issuance(currency_id: Self::CurrencyId) -> Self::Balance {
         log::trace!(
             target: "xcm::XCMApp",
             "total_issuance",
         );
+        if Self::is_held_locally(currency_id) {
+            return T::LocalAssets::total_issuance(currency_id);
+        }
         Default::default()
     }
 
-    fn total_balance(_currency_id: Self::CurrencyId, _who: &T::AccountId) -> Self::Balance {
+    fn total_balance(currency_id: Self::CurrencyId, who: &T::AccountId) -> Self::Balance {
         log::trace!(
             target: "xcm::XCMApp",
             "total_balance",
         );
+        if Self::is_held_locally(currency_id) {
+            return T::LocalAssets::total_balance(currency_id, who);
+        }
         Default::default()
     }
 
-    fn free_balance(_currency_id: Self::CurrencyId, _who: &T::AccountId) -> Self::Balance {
+    fn free_balance(currency_id: Self::CurrencyId, who: &T::AccountId) -> Self::Balance {
         log::trace!(
             target: "xcm::XCMApp",
             "free_balance",
         );
+        if Self::is_held_locally(currency_id) {
+            return T::LocalAssets::free_balance(currency_id, who);
+        }
         Default::default()
     }
 
     fn ensure_can_withdraw(
-        _currency_id: Self::CurrencyId,
-        _who: &T::AccountId,
-        _amount: Self::Balance,
+        currency_id: Self::CurrencyId,
+        who: &T::AccountId,
+        amount: Self::Balance,
     ) -> sp_runtime::DispatchResult {
         log::trace!(
             target: "xcm::XCMApp",
             "ensure_can_withdraw",
         );
+        if Self::is_held_locally(currency_id) {
+            return T::LocalAssets::ensure_can_withdraw(currency_id, who, amount);
+        }
         Ok(())
     }
 
     fn transfer(
-        _currency_id: Self::CurrencyId,
-        _from: &T::AccountId,
-        _to: &T::AccountId,
-        _amount: Self::Balance,
+        currency_id: Self::CurrencyId,
+        from: &T::AccountId,
+        to: &T::AccountId,
+        amount: Self::Balance,
     ) -> sp_runtime::DispatchResult {
         log::trace!(
             target: "xcm::XCMApp",
             "transfer",
         );
+        if Self::is_held_locally(currency_id) {
+            return T::LocalAssets::transfer(currency_id, from, to, amount);
+        }
         fail!(Error::<T>::MethodNotAvailible)
     }
 
@@ -104,6 +167,9 @@ impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
             target: "xcm::XCMApp",
             "deposit",
         );
+        if Self::is_held_locally(currency_id) {
+            return T::LocalAssets::deposit(currency_id, who, amount);
+        }
         Pallet::<T>::add_to_channel(
             who.clone(),
             currency_id,
@@ -113,34 +179,39 @@ impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
     }
 
     fn withdraw(
-        _currency_id: Self::CurrencyId,
-        _who: &T::AccountId,
-        _amount: Self::Balance,
+        currency_id: Self::CurrencyId,
+        who: &T::AccountId,
+        amount: Self::Balance,
     ) -> sp_runtime::DispatchResult {
         log::trace!(
             target: "xcm::XCMApp",
             "withdraw",
         );
+        if Self::is_held_locally(currency_id) {
+            return T::LocalAssets::withdraw(currency_id, who, amount);
+        }
         Ok(())
     }
 
-    fn can_slash(
-        _currency_id: Self::CurrencyId,
-        _who: &T::AccountId,
-        _value: Self::Balance,
-    ) -> bool {
+    fn can_slash(currency_id: Self::CurrencyId, who: &T::AccountId, value: Self::Balance) -> bool {
         log::trace!(
             target: "xcm::XCMApp",
             "can_slash",
         );
+        if Self::is_held_locally(currency_id) {
+            return T::LocalAssets::can_slash(currency_id, who, value);
+        }
         true
     }
 
     fn slash(
-        _currency_id: Self::CurrencyId,
-        _who: &T::AccountId,
-        _amount: Self::Balance,
+        currency_id: Self::CurrencyId,
+        who: &T::AccountId,
+        amount: Self::Balance,
     ) -> Self::Balance {
+        if Self::is_held_locally(currency_id) {
+            return T::LocalAssets::slash(currency_id, who, amount);
+        }
         Default::default()
     }
 }
@@ -37,24 +37,130 @@ mod mock;
 mod tests;
 
 mod impls;
+pub use impls::AllowedRecipients;
+
+pub mod migrations;
 
 pub mod weights;
 
 pub use pallet::*;
 
-use bridge_types::substrate::XCMAppCall;
-use frame_support::weights::Weight;
+use bridge_types::{substrate::XCMAppCall, SubNetworkId};
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+    storage::{with_transaction, TransactionOutcome},
+    weights::Weight,
+};
 use orml_traits::xcm_transfer::XcmTransfer;
 use orml_traits::MultiCurrency;
+use parachain_common::bridge::ParachainBridgeCall;
+pub use parachain_common::bridge::{TransactReport, XCMAppRemoteCall};
 use parachain_common::primitives::AssetId;
-use sp_runtime::AccountId32;
+use scale_info::TypeInfo;
+use sp_runtime::{AccountId32, RuntimeDebug};
 use xcm::{
     opaque::latest::{AssetId::Concrete, Fungibility::Fungible},
-    v3::{MultiAsset, MultiLocation},
+    v3::{
+        Junction::{AccountId32 as AccountId32Junction, GeneralIndex},
+        Junctions::X2,
+        MultiAsset, MultiLocation, NetworkId, QueryId,
+    },
 };
 
 pub type ParachainAssetId = xcm::VersionedMultiAsset;
 
+/// Identifier of an announced change
+pub type ChangeId = u32;
+
+/// Where the balances of a mapped asset are kept on this parachain
+#[derive(
+    Clone, Copy, Encode, Decode, MaxEncodedLen, PartialEq, Eq, RuntimeDebug, TypeInfo, Default,
+)]
+pub enum AssetPolicy {
+    /// Deposits are forwarded to SORA immediately, the asset is never held on this parachain
+    #[default]
+    ForwardToSora,
+    /// Deposits are credited to `Config::LocalAssets`, holders decide later where to send them
+    HoldLocally,
+}
+
+/// Type of the account junctions a chain accepts as the beneficiary of the transfers
+#[derive(
+    Clone, Copy, Encode, Decode, MaxEncodedLen, PartialEq, Eq, RuntimeDebug, TypeInfo, Default,
+)]
+pub enum BeneficiaryKind {
+    /// 32-byte accounts, `Junction::AccountId32`
+    #[default]
+    AccountId32,
+    /// 20-byte EVM accounts, `Junction::AccountKey20`
+    AccountKey20,
+}
+
+/// Change of the pallet state which is applied only after `Config::ChangeDelay` blocks
+/// since its announcement
+#[derive(Clone, Encode, Decode, MaxEncodedLen, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum DelayedChange {
+    /// Change multilocation mapped to the asset
+    ChangeAssetMapping { asset_id: AssetId, new_multilocation: MultiLocation },
+    /// Change asset mapped to the multilocation
+    ChangeMultilocationMapping { multilocation: MultiLocation, new_asset_id: AssetId },
+    /// Delete mapping of the asset
+    DeleteMapping { asset_id: AssetId },
+    /// Pause or resume transfers of the asset from this parachain
+    SetAssetPaused { asset_id: AssetId, paused: bool },
+    /// Set or remove the maximum amount of the asset transferred in a single transfer
+    SetTransferLimit { asset_id: AssetId, limit: Option<u128> },
+    /// Set where the balances of the asset are kept
+    SetAssetPolicy { asset_id: AssetId, policy: AssetPolicy },
+    /// Set the type of the accounts the destination chain accepts as the beneficiary
+    SetBeneficiaryKind { destination: MultiLocation, kind: BeneficiaryKind },
+    /// Allow or forbid the transfers to the destination chain
+    SetDestinationAllowed { destination: MultiLocation, allowed: bool },
+    /// Set or remove the existential deposit of the asset held on this parachain
+    SetExistentialDeposit { asset_id: AssetId, amount: Option<u128> },
+}
+
+/// Location of the SORA account of the network relative to this parachain
+pub fn sora_account_location(network_id: SubNetworkId, account: AccountId32) -> MultiLocation {
+    let network_index = network_id.encode()[0];
+    MultiLocation::new(
+        0,
+        X2(
+            GeneralIndex(network_index.into()),
+            AccountId32Junction { network: None, id: account.into() },
+        ),
+    )
+}
+
+/// SORA network and account of the location built by `sora_account_location`
+pub fn parse_sora_account_location(
+    location: &MultiLocation,
+) -> Option<(SubNetworkId, AccountId32)> {
+    match location {
+        MultiLocation {
+            parents: 0,
+            interior: X2(GeneralIndex(index), AccountId32Junction { network: None, id }),
+        } => {
+            let network_index = u8::try_from(*index).ok()?;
+            let network_id = SubNetworkId::decode(&mut &[network_index][..]).ok()?;
+            Some((network_id, (*id).into()))
+        },
+        _ => None,
+    }
+}
+
+/// Registers the queries notifying this pallet about the results of the remote `Transact`
+pub trait TransactQueryHandler<RuntimeCall, BlockNumber> {
+    /// Register the query answered by `responder` with the response sent on behalf of
+    /// `querier`, `notify` is dispatched with the response
+    fn new_notify_query(
+        responder: MultiLocation,
+        notify: RuntimeCall,
+        timeout: BlockNumber,
+        querier: MultiLocation,
+    ) -> QueryId;
+}
+
 pub trait WeightInfo {
     fn register_mapping() -> Weight;
 
@@ -67,6 +173,24 @@ pub trait WeightInfo {
     fn transfer() -> Weight;
 
     fn register_asset() -> Weight;
+
+    fn set_relay_network() -> Weight;
+
+    fn set_sora_network() -> Weight;
+
+    fn announce_change() -> Weight;
+
+    fn cancel_change() -> Weight;
+
+    fn apply_change() -> Weight;
+
+    fn send_to_sora() -> Weight;
+
+    fn send_to_parachain() -> Weight;
+
+    fn transact() -> Weight;
+
+    fn report_transact() -> Weight;
 }
 
 impl<T: Config> From<XCMAppCall> for Call<T>
@@ -85,6 +209,31 @@ where
     }
 }
 
+impl<T: Config> From<XCMAppRemoteCall> for Call<T>
+where
+    T::AccountId: From<AccountId32>,
+{
+    fn from(value: XCMAppRemoteCall) -> Self {
+        match value {
+            XCMAppRemoteCall::Transact {
+                sender,
+                destination,
+                fee_asset_id,
+                fee_amount,
+                require_weight_at_most,
+                call,
+            } => Call::transact {
+                sender: sender.into(),
+                destination,
+                fee_asset_id,
+                fee_amount,
+                require_weight_at_most,
+                call,
+            },
+        }
+    }
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
@@ -93,10 +242,37 @@ pub mod pallet {
         traits::OutboundChannel,
         SubNetworkId, H256,
     };
-    use frame_support::{dispatch::DispatchResultWithPostInfo, fail, pallet_prelude::*};
+    use frame_support::{
+        dispatch::DispatchResultWithPostInfo,
+        fail,
+        pallet_prelude::*,
+        traits::{
+            schedule::{v3::Named as ScheduleNamed, DispatchTime, HARD_DEADLINE},
+            StorePreimage,
+        },
+        Hashable,
+    };
     use frame_system::{pallet_prelude::*, RawOrigin};
     use parachain_common::primitives::AssetId;
-    use sp_runtime::traits::Convert;
+    use sp_runtime::traits::{Convert, Saturating, UniqueSaturatedInto, Zero};
+    use sp_std::prelude::*;
+    use xcm::v3::{
+        send_xcm,
+        Instruction::{
+            BuyExecution, DepositAsset, DescendOrigin, RefundSurplus, ReportError, SetAppendix,
+            Transact, WithdrawAsset,
+        },
+        InteriorMultiLocation, Junction,
+        Junctions::{Here, X1},
+        MultiAssetFilter, OriginKind, QueryResponseInfo, Response, SendXcm, WeightLimit,
+        WildMultiAsset, Xcm,
+    };
+
+    /// The in-code storage version
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+    /// Prefix of the scheduler task names of the announced changes
+    const CHANGE_TASK_PREFIX: &[u8] = b"xcm_app_change";
 
     #[pallet::config]
     pub trait Config: frame_system::Config {
@@ -120,6 +296,9 @@ pub mod pallet {
 
         type OutboundChannel: OutboundChannel<SubNetworkId, Self::AccountId, ()>;
 
+        /// Origin allowed to manage the pallet configuration and asset mappings
+        type ManagerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
         type AccountIdToMultiLocation: Convert<Self::AccountId, MultiLocation>;
 
         type XcmTransfer: XcmTransfer<Self::AccountId, u128, AssetId>;
@@ -127,22 +306,192 @@ pub mod pallet {
         type AccountIdConverter: Convert<Self::AccountId, AccountId32>;
 
         type BalanceConverter: Convert<Self::Balance, u128>;
+
+        /// The overarching call type, announced changes are scheduled as calls of this pallet
+        type RuntimeCall: Parameter
+            + From<Call<Self>>
+            + IsType<<Self as frame_system::Config>::RuntimeCall>;
+
+        /// The caller origin of the scheduled calls
+        type PalletsOrigin: From<RawOrigin<Self::AccountId>>;
+
+        /// Scheduler which applies announced changes after the delay
+        type Scheduler: ScheduleNamed<
+            Self::BlockNumber,
+            <Self as Config>::RuntimeCall,
+            Self::PalletsOrigin,
+        >;
+
+        /// Storage of the scheduled calls
+        type Preimages: StorePreimage;
+
+        /// Number of blocks between the announcement of a change and its execution
+        #[pallet::constant]
+        type ChangeDelay: Get<Self::BlockNumber>;
+
+        /// Origin allowed to cancel announced changes before they are applied
+        type CancelOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Ledger of the assets with `AssetPolicy::HoldLocally`
+        type LocalAssets: MultiCurrency<
+            Self::AccountId,
+            CurrencyId = AssetId,
+            Balance = Self::Balance,
+        >;
+
+        /// Router sending the remote `Transact` messages
+        type XcmSender: SendXcm;
+
+        /// Location of this parachain in the consensus universe
+        type UniversalLocation: Get<InteriorMultiLocation>;
+
+        /// Queries notifying this pallet about the results of the remote `Transact`
+        type TransactQueryHandler: TransactQueryHandler<
+            <Self as Config>::RuntimeCall,
+            Self::BlockNumber,
+        >;
+
+        /// Origin of the query responses, returns the location of the responder
+        type ResponseOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = MultiLocation>;
+
+        /// Maximum weight of the call transacted on a sibling parachain
+        #[pallet::constant]
+        type MaxTransactWeight: Get<Weight>;
+
+        /// Maximum length of the encoded call transacted on a sibling parachain
+        #[pallet::constant]
+        type MaxTransactCallLength: Get<u32>;
+
+        /// Number of blocks the result of the remote `Transact` is awaited
+        #[pallet::constant]
+        type TransactReportTimeout: Get<Self::BlockNumber>;
     }
 
     #[pallet::pallet]
     #[pallet::generate_store(pub(super) trait Store)]
-    #[pallet::without_storage_info]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
+    /// Multilocations of the mapped assets, iterable so that the mappings can be migrated
     #[pallet::storage]
     #[pallet::getter(fn get_multilocation_from_asset_id)]
     pub type AssetIdToMultilocation<T: Config> =
-        StorageMap<_, Blake2_256, AssetId, MultiLocation, OptionQuery>;
+        StorageMap<_, Blake2_128Concat, AssetId, MultiLocation, OptionQuery>;
 
+    /// Assets mapped to the multilocations, iterable so that the mappings can be migrated
     #[pallet::storage]
     #[pallet::getter(fn get_asset_id_from_multilocation)]
     pub type MultilocationToAssetId<T: Config> =
-        StorageMap<_, Blake2_256, MultiLocation, AssetId, OptionQuery>;
+        StorageMap<_, Blake2_128Concat, MultiLocation, AssetId, OptionQuery>;
+
+    #[pallet::type_value]
+    pub fn DefaultRelayNetwork() -> NetworkId {
+        NetworkId::Rococo
+    }
+
+    /// Network of the relay chain this parachain is connected to
+    #[pallet::storage]
+    #[pallet::getter(fn relay_network)]
+    pub type RelayNetwork<T: Config> = StorageValue<_, NetworkId, ValueQuery, DefaultRelayNetwork>;
+
+    #[pallet::type_value]
+    pub fn DefaultSoraNetwork() -> SubNetworkId {
+        SubNetworkId::Mainnet
+    }
+
+    /// SORA network the bridge messages are sent to
+    #[pallet::storage]
+    #[pallet::getter(fn sora_network)]
+    pub type SoraNetwork<T: Config> = StorageValue<_, SubNetworkId, ValueQuery, DefaultSoraNetwork>;
+
+    /// Assets which transfers from this parachain are paused
+    #[pallet::storage]
+    #[pallet::getter(fn is_asset_paused)]
+    pub type PausedAssets<T: Config> = StorageMap<_, Blake2_128Concat, AssetId, bool, ValueQuery>;
+
+    /// Maximum amount of an asset transferred from this parachain in a single transfer
+    #[pallet::storage]
+    #[pallet::getter(fn transfer_limit)]
+    pub type TransferLimits<T: Config> =
+        StorageMap<_, Blake2_128Concat, AssetId, u128, OptionQuery>;
+
+    #[pallet::storage]
+    pub type NextChangeId<T: Config> = StorageValue<_, ChangeId, ValueQuery>;
+
+    /// Announced changes and the blocks they are applied at
+    #[pallet::storage]
+    #[pallet::getter(fn pending_change)]
+    pub type PendingChanges<T: Config> =
+        StorageMap<_, Twox64Concat, ChangeId, (DelayedChange, T::BlockNumber), OptionQuery>;
+
+    /// Existential deposits of the assets held on this parachain, the runtime decides on the
+    /// assets which are not listed
+    #[pallet::storage]
+    #[pallet::getter(fn existential_deposit)]
+    pub type ExistentialDeposits<T: Config> =
+        StorageMap<_, Blake2_128Concat, AssetId, u128, OptionQuery>;
+
+    /// Where the balances of the assets are kept
+    #[pallet::storage]
+    #[pallet::getter(fn asset_policy)]
+    pub type AssetPolicies<T: Config> =
+        StorageMap<_, Blake2_128Concat, AssetId, AssetPolicy, ValueQuery>;
+
+    /// Type of the beneficiary accounts of the destination chains, the chains which are not
+    /// listed accept `BeneficiaryKind::AccountId32`
+    #[pallet::storage]
+    #[pallet::getter(fn beneficiary_kind)]
+    pub type BeneficiaryKinds<T: Config> =
+        StorageMap<_, Blake2_128Concat, MultiLocation, BeneficiaryKind, ValueQuery>;
+
+    /// Destination chains the assets can be transferred to, the relay chain or the sibling
+    /// parachains
+    #[pallet::storage]
+    #[pallet::getter(fn is_destination_allowed)]
+    pub type AllowedDestinations<T: Config> =
+        StorageMap<_, Blake2_128Concat, MultiLocation, bool, ValueQuery>;
+
+    /// SORA accounts the derived parachain accounts belong to,
+    /// [Derived AccountId] -> (SubNetworkId, SORA AccountId)
+    #[pallet::storage]
+    #[pallet::getter(fn sora_account)]
+    pub type SoraAccounts<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, (SubNetworkId, AccountId32), OptionQuery>;
+
+    /// Remote `Transact` messages awaiting the result, [QueryId] -> (Sender, Destination)
+    #[pallet::storage]
+    #[pallet::getter(fn pending_transact)]
+    pub type PendingTransacts<T: Config> =
+        StorageMap<_, Twox64Concat, QueryId, (T::AccountId, MultiLocation), OptionQuery>;
+
+    #[pallet::genesis_config]
+    pub struct GenesisConfig {
+        pub relay_network: NetworkId,
+        pub sora_network: SubNetworkId,
+        pub allowed_destinations: Vec<MultiLocation>,
+    }
+
+    #[cfg(feature = "std")]
+    impl Default for GenesisConfig {
+        fn default() -> Self {
+            Self {
+                relay_network: DefaultRelayNetwork::get(),
+                sora_network: DefaultSoraNetwork::get(),
+                allowed_destinations: vec![MultiLocation::parent()],
+            }
+        }
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> GenesisBuild<T> for GenesisConfig {
+        fn build(&self) {
+            RelayNetwork::<T>::put(self.relay_network);
+            SoraNetwork::<T>::put(self.sora_network);
+            for destination in &self.allowed_destinations {
+                AllowedDestinations::<T>::insert(destination, true);
+            }
+        }
+    }
 
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -165,6 +514,48 @@ pub mod pallet {
         /// Asset transfered from this parachain
         /// [From, To, AssedId, amount]
         AssetTransferred(T::AccountId, MultiLocation, AssetId, u128),
+        /// Relay chain network has been changed
+        /// [NetworkId]
+        RelayNetworkChanged(NetworkId),
+        /// SORA network has been changed
+        /// [SubNetworkId]
+        SoraNetworkChanged(SubNetworkId),
+        /// Change has been announced and will be applied at the given block
+        /// [ChangeId, DelayedChange, BlockNumber]
+        ChangeAnnounced(ChangeId, DelayedChange, T::BlockNumber),
+        /// Announced change has been cancelled
+        /// [ChangeId]
+        ChangeCancelled(ChangeId),
+        /// Announced change has been applied
+        /// [ChangeId, Result]
+        ChangeApplied(ChangeId, DispatchResult),
+        /// Transfers of an asset from this parachain have been paused or resumed
+        /// [AssetId, Paused]
+        AssetPauseChanged(AssetId, bool),
+        /// Transfer limit of an asset has been changed
+        /// [AssetId, Limit]
+        TransferLimitChanged(AssetId, Option<u128>),
+        /// Policy of an asset has been changed
+        /// [AssetId, AssetPolicy]
+        AssetPolicyChanged(AssetId, AssetPolicy),
+        /// Type of the beneficiary accounts of a destination chain has been changed
+        /// [Destination, BeneficiaryKind]
+        BeneficiaryKindChanged(MultiLocation, BeneficiaryKind),
+        /// Transfers to a destination chain have been allowed or forbidden
+        /// [Destination, Allowed]
+        DestinationAllowanceChanged(MultiLocation, bool),
+        /// Transfer from SORA has been rejected and the asset has been sent back
+        /// [SORA Account, AssetId, amount, Error]
+        AssetRefunded(AccountId32, AssetId, u128, DispatchError),
+        /// Call has been sent to be transacted on a sibling parachain
+        /// [Sender, Destination, QueryId]
+        TransactSent(T::AccountId, MultiLocation, QueryId),
+        /// Result of the remote `Transact` has been reported to SORA
+        /// [QueryId, Error]
+        TransactReported(QueryId, Option<(u32, xcm::v3::Error)>),
+        /// Existential deposit of an asset has been changed
+        /// [AssetId, Amount]
+        ExistentialDepositChanged(AssetId, Option<u128>),
 
         // Error events:
         /// Error while submitting to outbound channel
@@ -191,6 +582,36 @@ pub mod pallet {
         WrongXCMVersion,
         /// Error with mapping during tranfer assets from parachain to other parachans
         InvalidMultilocationMapping,
+        /// No announced change with the given id
+        ChangeNotFound,
+        /// Transfers of the asset are paused
+        AssetPaused,
+        /// Transfer amount exceeds the limit of the asset
+        TransferLimitExceeded,
+        /// Asset is not held on this parachain
+        AssetNotHeldLocally,
+        /// Asset still has balances on this parachain
+        LocalBalancesExist,
+        /// Destination chain does not accept the type of the beneficiary account
+        InvalidBeneficiary,
+        /// Recipient is not an account on the relay chain or a sibling parachain
+        InvalidRecipient,
+        /// Transfers to the destination chain are not allowed
+        DestinationNotAllowed,
+        /// Location is not the relay chain or a sibling parachain
+        InvalidDestination,
+        /// Calls can be transacted only on the sibling parachains
+        InvalidTransactDestination,
+        /// Weight of the remote call exceeds the limit
+        TransactWeightExceeded,
+        /// Encoded remote call is too long
+        TransactCallTooLong,
+        /// Message to the sibling parachain can not be sent
+        TransactSendFailure,
+        /// No remote `Transact` awaits the response of the query
+        UnknownTransactQuery,
+        /// Response of the query is not the result of the execution
+        UnexpectedTransactResponse,
     }
 
     #[pallet::hooks]
@@ -213,7 +634,29 @@ pub mod pallet {
                 (asset_id, sender.clone(), recipient.clone(), amount),
                 res
             );
-            Self::do_xcm_asset_transfer(asset_id, sender, recipient, amount)?;
+            let sora_account = T::AccountIdConverter::convert(sender);
+            // Checked before anything is written, so that a rejected transfer leaves no trace
+            let recipient = match Self::ensure_transfer_allowed(asset_id, &recipient, amount) {
+                Ok(recipient) => recipient,
+                Err(e) => {
+                    // The asset is already locked on SORA, so it goes back instead of failing
+                    Self::refund_to_sora(res.network_id, sora_account, asset_id, amount, e)?;
+                    return Ok(().into());
+                },
+            };
+            let sender = Self::register_sora_account(res.network_id, sora_account.clone());
+            // The deposit is rolled back if the asset can not be sent
+            let result = with_transaction(|| {
+                match Self::deposit_and_send(asset_id, sender, recipient, amount) {
+                    Ok(()) => TransactionOutcome::Commit(Ok(())),
+                    Err(e) => TransactionOutcome::Rollback(Err(e)),
+                }
+            });
+            if let Err(e) = result {
+                // Nothing is left on this parachain, the asset goes back to SORA
+                Self::deposit_event(Event::<T>::TrasferringAssetError(e, asset_id));
+                Self::refund_to_sora(res.network_id, sora_account, asset_id, amount, e)?;
+            }
             Ok(().into())
         }
 
@@ -231,22 +674,212 @@ pub mod pallet {
                 (asset_id, multiasset.clone()),
                 res
             );
-            let multilocation = match multiasset {
-                xcm::v3::AssetId::Concrete(location) => location,
-                xcm::v3::AssetId::Abstract(_) => fail!(Error::<T>::WrongXCMVersion),
-            };
+            Self::do_register_asset(res.network_id, asset_id, multiasset, asset_kind)?;
+            Ok(().into())
+        }
 
-            Self::register_mapping(asset_id, multilocation)?;
+        #[pallet::call_index(2)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_relay_network())]
+        pub fn set_relay_network(
+            origin: OriginFor<T>,
+            network: NetworkId,
+        ) -> DispatchResultWithPostInfo {
+            T::ManagerOrigin::ensure_origin(origin)?;
+            RelayNetwork::<T>::put(network);
+            Self::deposit_event(Event::<T>::RelayNetworkChanged(network));
+            Ok(().into())
+        }
 
-            T::OutboundChannel::submit(
-                SubNetworkId::Mainnet,
-                &RawOrigin::Root,
-                &SubstrateAppCall::FinalizeAssetRegistration { asset_id, asset_kind }
-                    .prepare_message(),
-                (),
+        #[pallet::call_index(3)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_sora_network())]
+        pub fn set_sora_network(
+            origin: OriginFor<T>,
+            network: SubNetworkId,
+        ) -> DispatchResultWithPostInfo {
+            T::ManagerOrigin::ensure_origin(origin)?;
+            SoraNetwork::<T>::put(network);
+            Self::deposit_event(Event::<T>::SoraNetworkChanged(network));
+            Ok(().into())
+        }
+
+        /// Announce a change which is applied after `ChangeDelay` blocks
+        ///
+        /// - `change`: the change to apply,
+        #[pallet::call_index(4)]
+        #[pallet::weight(<T as Config>::WeightInfo::announce_change())]
+        pub fn announce_change(
+            origin: OriginFor<T>,
+            change: DelayedChange,
+        ) -> DispatchResultWithPostInfo {
+            T::ManagerOrigin::ensure_origin(origin)?;
+            let change_id = NextChangeId::<T>::mutate(|next_id| {
+                let change_id = *next_id;
+                *next_id = next_id.wrapping_add(1);
+                change_id
+            });
+            let execute_at =
+                frame_system::Pallet::<T>::block_number().saturating_add(T::ChangeDelay::get());
+            let call = <T as Config>::RuntimeCall::from(Call::<T>::apply_change { change_id });
+            T::Scheduler::schedule_named(
+                Self::change_task_name(change_id),
+                DispatchTime::At(execute_at),
+                None,
+                HARD_DEADLINE,
+                RawOrigin::Root.into(),
+                T::Preimages::bound(call)?,
             )?;
+            PendingChanges::<T>::insert(change_id, (change.clone(), execute_at));
+            Self::deposit_event(Event::<T>::ChangeAnnounced(change_id, change, execute_at));
+            Ok(().into())
+        }
 
-            Self::deposit_event(Event::<T>::MappingCreated(asset_id, multilocation));
+        /// Cancel an announced change before it is applied
+        ///
+        /// - `change_id`: id of the announced change,
+        #[pallet::call_index(5)]
+        #[pallet::weight(<T as Config>::WeightInfo::cancel_change())]
+        pub fn cancel_change(
+            origin: OriginFor<T>,
+            change_id: ChangeId,
+        ) -> DispatchResultWithPostInfo {
+            T::CancelOrigin::ensure_origin(origin)?;
+            ensure!(PendingChanges::<T>::contains_key(change_id), Error::<T>::ChangeNotFound);
+            T::Scheduler::cancel_named(Self::change_task_name(change_id))?;
+            PendingChanges::<T>::remove(change_id);
+            Self::deposit_event(Event::<T>::ChangeCancelled(change_id));
+            Ok(().into())
+        }
+
+        /// Apply an announced change, dispatched by the scheduler once the delay has passed
+        ///
+        /// - `change_id`: id of the announced change,
+        #[pallet::call_index(6)]
+        #[pallet::weight(<T as Config>::WeightInfo::apply_change())]
+        pub fn apply_change(
+            origin: OriginFor<T>,
+            change_id: ChangeId,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            let (change, _) =
+                PendingChanges::<T>::take(change_id).ok_or(Error::<T>::ChangeNotFound)?;
+            let result = Self::do_apply_change(change);
+            Self::deposit_event(Event::<T>::ChangeApplied(change_id, result));
+            Ok(().into())
+        }
+
+        /// Send the asset held on this parachain to the same account on SORA
+        ///
+        /// - `asset_id`: asset id in Sora Network,
+        /// - `amount`: amount to send,
+        #[pallet::call_index(7)]
+        #[pallet::weight(<T as Config>::WeightInfo::send_to_sora())]
+        pub fn send_to_sora(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+            amount: T::Balance,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(Self::is_held_locally(asset_id), Error::<T>::AssetNotHeldLocally);
+            Self::ensure_asset_transferable(asset_id, T::BalanceConverter::convert(amount))?;
+            T::LocalAssets::withdraw(asset_id, &who, amount)?;
+            Self::add_to_channel(who, asset_id, T::BalanceConverter::convert(amount))?;
+            Ok(().into())
+        }
+
+        /// Send the asset held on this parachain to another chain
+        ///
+        /// - `asset_id`: asset id in Sora Network,
+        /// - `recipient`: XCM multilocation of the recipient,
+        /// - `amount`: amount to send,
+        #[pallet::call_index(8)]
+        #[pallet::weight(<T as Config>::WeightInfo::send_to_parachain())]
+        pub fn send_to_parachain(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+            recipient: xcm::VersionedMultiLocation,
+            amount: T::Balance,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(Self::is_held_locally(asset_id), Error::<T>::AssetNotHeldLocally);
+            Self::do_xcm_asset_transfer(
+                asset_id,
+                who,
+                recipient,
+                T::BalanceConverter::convert(amount),
+            )?;
+            Ok(().into())
+        }
+
+        /// Transact the call on a sibling parachain on behalf of the SORA account, the fees are
+        /// paid on the destination from the account of the sender location descended from this
+        /// parachain and the result is reported back to SORA
+        ///
+        /// - `sender`: account in Sora Network,
+        /// - `destination`: XCM multilocation of the sibling parachain,
+        /// - `fee_asset_id`: asset id in Sora Network of the asset paying the fees,
+        /// - `fee_amount`: amount of the fee asset withdrawn on the destination,
+        /// - `require_weight_at_most`: weight of the call on the destination,
+        /// - `call`: encoded call of the destination,
+        #[pallet::call_index(9)]
+        #[pallet::weight(<T as Config>::WeightInfo::transact())]
+        pub fn transact(
+            origin: OriginFor<T>,
+            sender: T::AccountId,
+            destination: xcm::VersionedMultiLocation,
+            fee_asset_id: AssetId,
+            fee_amount: u128,
+            require_weight_at_most: Weight,
+            call: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            let res = T::CallOrigin::ensure_origin(origin)?;
+            frame_support::log::info!(
+                "Call transact with params: {:?} by {:?}",
+                (sender.clone(), destination.clone(), fee_asset_id, fee_amount),
+                res
+            );
+            let sora_account = T::AccountIdConverter::convert(sender);
+            let sender = Self::sora_account_id(res.network_id, sora_account.clone());
+            Self::do_transact(
+                sender,
+                destination,
+                fee_asset_id,
+                fee_amount,
+                require_weight_at_most,
+                call,
+            )?;
+            Self::register_sora_account(res.network_id, sora_account);
+            Ok(().into())
+        }
+
+        /// Report the result of the remote `Transact` to SORA, dispatched with the response
+        /// of the query
+        ///
+        /// - `query_id`: query of the remote `Transact`,
+        /// - `response`: response of the destination,
+        #[pallet::call_index(10)]
+        #[pallet::weight(<T as Config>::WeightInfo::report_transact())]
+        pub fn report_transact(
+            origin: OriginFor<T>,
+            query_id: QueryId,
+            response: Response,
+        ) -> DispatchResultWithPostInfo {
+            let responder = T::ResponseOrigin::ensure_origin(origin)?;
+            let (sender, destination) =
+                Self::pending_transact(query_id).ok_or(Error::<T>::UnknownTransactQuery)?;
+            ensure!(responder == destination, DispatchError::BadOrigin);
+            let error = match response {
+                Response::ExecutionResult(error) => error,
+                _ => fail!(Error::<T>::UnexpectedTransactResponse),
+            };
+            PendingTransacts::<T>::remove(query_id);
+            let (network_id, sender) = Self::sora_recipient(&sender);
+            let report = ParachainBridgeCall::XCMAppTransactReport(TransactReport {
+                sender,
+                query_id,
+                error,
+            });
+            T::OutboundChannel::submit(network_id, &RawOrigin::Root, &report.encode(), ())?;
+            Self::deposit_event(Event::<T>::TransactReported(query_id, error));
             Ok(().into())
         }
     }
@@ -258,23 +891,43 @@ pub mod pallet {
             amount: u128,
         ) -> sp_runtime::DispatchResult {
             let raw_origin = Some(account_id.clone()).into();
+            let (network_id, recipient) = Self::sora_recipient(&account_id);
+            let xcm_mes = SubstrateAppCall::Transfer { asset_id, recipient, sender: None, amount };
+            let xcm_mes_bytes = xcm_mes.clone().prepare_message();
+            if let Err(e) =
+                <T as Config>::OutboundChannel::submit(network_id, &raw_origin, &xcm_mes_bytes, ())
+            {
+                Self::deposit_event(Event::<T>::SubmittingToChannelError(e, asset_id));
+                return Err(e);
+            }
+            Self::deposit_event(Event::<T>::AssetAddedToChannel(xcm_mes));
+            Ok(())
+        }
+
+        /// Send the asset of the rejected transfer back to the SORA account
+        pub fn refund_to_sora(
+            network_id: SubNetworkId,
+            recipient: AccountId32,
+            asset_id: AssetId,
+            amount: u128,
+            error: DispatchError,
+        ) -> DispatchResult {
             let xcm_mes = SubstrateAppCall::Transfer {
                 asset_id,
-                recipient: T::AccountIdConverter::convert(account_id),
+                recipient: recipient.clone(),
                 sender: None,
                 amount,
             };
-            let xcm_mes_bytes = xcm_mes.clone().prepare_message();
             if let Err(e) = <T as Config>::OutboundChannel::submit(
-                SubNetworkId::Mainnet,
-                &raw_origin,
-                &xcm_mes_bytes,
+                network_id,
+                &RawOrigin::Root,
+                &xcm_mes.prepare_message(),
                 (),
             ) {
                 Self::deposit_event(Event::<T>::SubmittingToChannelError(e, asset_id));
                 return Err(e);
             }
-            Self::deposit_event(Event::<T>::AssetAddedToChannel(xcm_mes));
+            Self::deposit_event(Event::<T>::AssetRefunded(recipient, asset_id, amount, error));
             Ok(())
         }
 
@@ -284,26 +937,310 @@ pub mod pallet {
             recipient: xcm::VersionedMultiLocation,
             amount: u128,
         ) -> sp_runtime::DispatchResult {
-            let recipient = match recipient {
-                xcm::VersionedMultiLocation::V3(m) => m,
-                _ => fail!(Error::<T>::WrongXCMVersion),
-            };
-            if let Err(e) = <T as Config>::XcmTransfer::transfer(
-                sender.clone(),
-                asset_id,
-                amount,
-                recipient.clone(),
-                xcm::v3::WeightLimit::Unlimited,
-            ) {
+            let recipient = Self::ensure_transfer_allowed(asset_id, &recipient, amount)?;
+            if let Err(e) = Self::send_xcm_transfer(asset_id, sender, recipient, amount) {
                 Self::deposit_event(Event::<T>::TrasferringAssetError(e, asset_id));
                 // dbushuev: the refund will be done via https://app.zenhub.com/workspaces/sora2-backend-62b9c0e3e9b9e600201273e3/issues/gh/sora-xor/sora2-parachain/106
                 return Err(e);
             }
+            Ok(())
+        }
+
+        /// Send the asset locked on SORA to the recipient checked by `ensure_transfer_allowed`
+        fn deposit_and_send(
+            asset_id: AssetId,
+            sender: T::AccountId,
+            recipient: MultiLocation,
+            amount: u128,
+        ) -> DispatchResult {
+            if Self::is_held_locally(asset_id) {
+                // The asset is locked on SORA, so it is minted to the sender before it leaves
+                T::LocalAssets::deposit(asset_id, &sender, amount.unique_saturated_into())?;
+            }
+            Self::send_xcm_transfer(asset_id, sender, recipient, amount)
+        }
 
+        /// Send the asset to the recipient checked by `ensure_transfer_allowed`
+        fn send_xcm_transfer(
+            asset_id: AssetId,
+            sender: T::AccountId,
+            recipient: MultiLocation,
+            amount: u128,
+        ) -> DispatchResult {
+            <T as Config>::XcmTransfer::transfer(
+                sender.clone(),
+                asset_id,
+                amount,
+                recipient,
+                xcm::v3::WeightLimit::Unlimited,
+            )?;
             Self::deposit_event(Event::<T>::AssetTransferred(sender, recipient, asset_id, amount));
             Ok(())
         }
 
+        /// Ensure the asset can be transferred to the recipient, returns the recipient location
+        pub fn ensure_transfer_allowed(
+            asset_id: AssetId,
+            recipient: &xcm::VersionedMultiLocation,
+            amount: u128,
+        ) -> Result<MultiLocation, DispatchError> {
+            Self::ensure_asset_transferable(asset_id, amount)?;
+            Self::ensure_valid_recipient(recipient)
+        }
+
+        /// Ensure the transfers of the asset are not paused and the amount is within the limit
+        pub fn ensure_asset_transferable(asset_id: AssetId, amount: u128) -> DispatchResult {
+            ensure!(!Self::is_asset_paused(asset_id), Error::<T>::AssetPaused);
+            if let Some(limit) = Self::transfer_limit(asset_id) {
+                ensure!(amount <= limit, Error::<T>::TransferLimitExceeded);
+            }
+            Ok(())
+        }
+
+        /// Ensure the recipient is an account on an allowed destination chain, returns the
+        /// recipient location
+        pub fn ensure_valid_recipient(
+            recipient: &xcm::VersionedMultiLocation,
+        ) -> Result<MultiLocation, DispatchError> {
+            let recipient = match recipient {
+                xcm::VersionedMultiLocation::V3(m) => *m,
+                _ => fail!(Error::<T>::WrongXCMVersion),
+            };
+            Self::ensure_valid_recipient_location(&recipient)?;
+            Ok(recipient)
+        }
+
+        /// Ensure the location is `(1, X1(account))` or `(1, X2(Parachain(id), account))` with
+        /// the account of the type the allowed destination chain accepts
+        pub fn ensure_valid_recipient_location(recipient: &MultiLocation) -> DispatchResult {
+            let (destination, beneficiary) = recipient.split_last_interior();
+            ensure!(Self::is_valid_destination(&destination), Error::<T>::InvalidRecipient);
+            let (kind, network) = match beneficiary {
+                Some(Junction::AccountId32 { network, .. }) => {
+                    (BeneficiaryKind::AccountId32, network)
+                },
+                Some(Junction::AccountKey20 { network, .. }) => {
+                    (BeneficiaryKind::AccountKey20, network)
+                },
+                _ => fail!(Error::<T>::InvalidRecipient),
+            };
+            ensure!(
+                network.map_or(true, |network| network == Self::relay_network()),
+                Error::<T>::InvalidRecipient
+            );
+            ensure!(Self::is_destination_allowed(destination), Error::<T>::DestinationNotAllowed);
+            ensure!(Self::beneficiary_kind(destination) == kind, Error::<T>::InvalidBeneficiary);
+            Ok(())
+        }
+
+        /// Whether the location is the relay chain or a sibling parachain
+        pub fn is_valid_destination(location: &MultiLocation) -> bool {
+            matches!(
+                location,
+                MultiLocation { parents: 1, interior: Here }
+                    | MultiLocation { parents: 1, interior: X1(Junction::Parachain(_)) }
+            )
+        }
+
+        /// Send the call to be transacted on the sibling parachain, returns the query of the
+        /// result
+        pub fn do_transact(
+            sender: T::AccountId,
+            destination: xcm::VersionedMultiLocation,
+            fee_asset_id: AssetId,
+            fee_amount: u128,
+            require_weight_at_most: Weight,
+            call: Vec<u8>,
+        ) -> Result<QueryId, DispatchError> {
+            let destination = match destination {
+                xcm::VersionedMultiLocation::V3(m) => m,
+                _ => fail!(Error::<T>::WrongXCMVersion),
+            };
+            ensure!(
+                matches!(
+                    destination,
+                    MultiLocation { parents: 1, interior: X1(Junction::Parachain(_)) }
+                ),
+                Error::<T>::InvalidTransactDestination
+            );
+            ensure!(
+                require_weight_at_most.all_lte(T::MaxTransactWeight::get()),
+                Error::<T>::TransactWeightExceeded
+            );
+            ensure!(
+                call.len() <= T::MaxTransactCallLength::get() as usize,
+                Error::<T>::TransactCallTooLong
+            );
+            ensure!(!Self::is_asset_paused(fee_asset_id), Error::<T>::AssetPaused);
+            if let Some(limit) = Self::transfer_limit(fee_asset_id) {
+                ensure!(fee_amount <= limit, Error::<T>::TransferLimitExceeded);
+            }
+
+            let universal_location = T::UniversalLocation::get();
+            let fee_location = Self::get_multilocation_from_asset_id(fee_asset_id)
+                .ok_or(Error::<T>::MappingNotExist)?
+                .reanchored(&destination, universal_location)
+                .map_err(|_| Error::<T>::InvalidMultilocationMapping)?;
+            // Location of this parachain as seen from the destination
+            let self_location = universal_location
+                .invert_target(&destination)
+                .map_err(|_| Error::<T>::InvalidTransactDestination)?;
+            let sender_junction = Junction::AccountId32 {
+                network: None,
+                id: T::AccountIdConverter::convert(sender.clone()).into(),
+            };
+            let sender_location = self_location
+                .pushed_with_interior(sender_junction)
+                .map_err(|_| Error::<T>::InvalidTransactDestination)?;
+
+            // The response is sent on behalf of the descended origin
+            let query_id = T::TransactQueryHandler::new_notify_query(
+                destination,
+                <T as Config>::RuntimeCall::from(Call::<T>::report_transact {
+                    query_id: Default::default(),
+                    response: Response::Null,
+                }),
+                frame_system::Pallet::<T>::block_number()
+                    .saturating_add(T::TransactReportTimeout::get()),
+                X1(sender_junction).into(),
+            );
+            let fee: MultiAsset = (fee_location, fee_amount).into();
+            let message = Xcm(vec![
+                DescendOrigin(X1(sender_junction)),
+                WithdrawAsset(fee.clone().into()),
+                BuyExecution { fees: fee, weight_limit: WeightLimit::Unlimited },
+                // Executed even if the call fails, so that the surplus is returned to the sender
+                // and the error is reported
+                SetAppendix(Xcm(vec![
+                    RefundSurplus,
+                    DepositAsset {
+                        assets: MultiAssetFilter::Wild(WildMultiAsset::AllCounted(1)),
+                        beneficiary: sender_location,
+                    },
+                    ReportError(QueryResponseInfo {
+                        destination: self_location,
+                        query_id,
+                        max_weight: T::WeightInfo::report_transact(),
+                    }),
+                ])),
+                Transact {
+                    origin_kind: OriginKind::SovereignAccount,
+                    require_weight_at_most,
+                    call: call.into(),
+                },
+            ]);
+            send_xcm::<T::XcmSender>(destination, message)
+                .map_err(|_| Error::<T>::TransactSendFailure)?;
+
+            PendingTransacts::<T>::insert(query_id, (sender.clone(), destination));
+            Self::deposit_event(Event::<T>::TransactSent(sender, destination, query_id));
+            Ok(query_id)
+        }
+
+        /// Register mapping for an asset and notify SORA that the registration is finalized
+        ///
+        /// - `network_id`: SORA network the registration result is sent to,
+        /// - `asset_id`: asset id in Sora Network,
+        /// - `multiasset`: XCM asset id, only `Concrete` is supported,
+        /// - `asset_kind`: kind of the asset reported back to Sora Network,
+        pub fn do_register_asset(
+            network_id: SubNetworkId,
+            asset_id: AssetId,
+            multiasset: xcm::v3::AssetId,
+            asset_kind: bridge_types::types::AssetKind,
+        ) -> sp_runtime::DispatchResult {
+            let multilocation = match multiasset {
+                xcm::v3::AssetId::Concrete(location) => location,
+                xcm::v3::AssetId::Abstract(_) => fail!(Error::<T>::WrongXCMVersion),
+            };
+
+            Self::register_mapping(asset_id, multilocation).map_err(|e| e.error)?;
+
+            T::OutboundChannel::submit(
+                network_id,
+                &RawOrigin::Root,
+                &SubstrateAppCall::FinalizeAssetRegistration { asset_id, asset_kind }
+                    .prepare_message(),
+                (),
+            )?;
+
+            Self::deposit_event(Event::<T>::MappingCreated(asset_id, multilocation));
+            Ok(())
+        }
+
+        /// Scheduler task name of the announced change
+        pub fn change_task_name(change_id: ChangeId) -> [u8; 32] {
+            (CHANGE_TASK_PREFIX, change_id).blake2_256()
+        }
+
+        /// Perform the announced change
+        ///
+        /// - `change`: the change to apply,
+        pub fn do_apply_change(change: DelayedChange) -> DispatchResult {
+            match change {
+                DelayedChange::ChangeAssetMapping { asset_id, new_multilocation } => {
+                    Self::change_asset_mapping(asset_id, new_multilocation).map_err(|e| e.error)?;
+                },
+                DelayedChange::ChangeMultilocationMapping { multilocation, new_asset_id } => {
+                    Self::change_multilocation_mapping(multilocation, new_asset_id)
+                        .map_err(|e| e.error)?;
+                },
+                DelayedChange::DeleteMapping { asset_id } => {
+                    Self::delete_mapping(asset_id).map_err(|e| e.error)?;
+                },
+                DelayedChange::SetAssetPaused { asset_id, paused } => {
+                    if paused {
+                        PausedAssets::<T>::insert(asset_id, true);
+                    } else {
+                        PausedAssets::<T>::remove(asset_id);
+                    }
+                    Self::deposit_event(Event::<T>::AssetPauseChanged(asset_id, paused));
+                },
+                DelayedChange::SetTransferLimit { asset_id, limit } => {
+                    TransferLimits::<T>::set(asset_id, limit);
+                    Self::deposit_event(Event::<T>::TransferLimitChanged(asset_id, limit));
+                },
+                DelayedChange::SetAssetPolicy { asset_id, policy } => {
+                    // Balances held here would be lost if deposits started going to SORA
+                    ensure!(
+                        policy == AssetPolicy::HoldLocally
+                            || T::LocalAssets::total_issuance(asset_id).is_zero(),
+                        Error::<T>::LocalBalancesExist
+                    );
+                    AssetPolicies::<T>::insert(asset_id, policy);
+                    Self::deposit_event(Event::<T>::AssetPolicyChanged(asset_id, policy));
+                },
+                DelayedChange::SetBeneficiaryKind { destination, kind } => {
+                    if kind == BeneficiaryKind::default() {
+                        BeneficiaryKinds::<T>::remove(destination);
+                    } else {
+                        BeneficiaryKinds::<T>::insert(destination, kind);
+                    }
+                    Self::deposit_event(Event::<T>::BeneficiaryKindChanged(destination, kind));
+                },
+                DelayedChange::SetDestinationAllowed { destination, allowed } => {
+                    ensure!(
+                        Self::is_valid_destination(&destination),
+                        Error::<T>::InvalidDestination
+                    );
+                    if allowed {
+                        AllowedDestinations::<T>::insert(destination, true);
+                    } else {
+                        AllowedDestinations::<T>::remove(destination);
+                    }
+                    Self::deposit_event(Event::<T>::DestinationAllowanceChanged(
+                        destination,
+                        allowed,
+                    ));
+                },
+                DelayedChange::SetExistentialDeposit { asset_id, amount } => {
+                    ExistentialDeposits::<T>::set(asset_id, amount);
+                    Self::deposit_event(Event::<T>::ExistentialDepositChanged(asset_id, amount));
+                },
+            }
+            Ok(())
+        }
+
         /// Perform registration for mapping of an AssetId <-> Multilocation
         ///
         /// - `asset_id`: asset id in Sora Network,
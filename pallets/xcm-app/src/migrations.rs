@@ -0,0 +1,129 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use crate::*;
+use frame_support::{
+    log,
+    migration::{clear_storage_prefix, storage_iter},
+    pallet_prelude::*,
+    storage_alias,
+    traits::{OnRuntimeUpgrade, PalletInfoAccess, StorageVersion},
+};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+pub mod v1 {
+    use super::*;
+
+    /// Mappings before v1, their `Blake2_256` keys can not be iterated
+    pub mod old {
+        use super::*;
+
+        #[storage_alias]
+        pub type AssetIdToMultilocation<T: Config> =
+            StorageMap<Pallet<T>, Blake2_256, AssetId, MultiLocation, OptionQuery>;
+
+        #[storage_alias]
+        pub type MultilocationToAssetId<T: Config> =
+            StorageMap<Pallet<T>, Blake2_256, MultiLocation, AssetId, OptionQuery>;
+    }
+
+    /// Mappings stored before v1, the asset ids are taken from the values of
+    /// `MultilocationToAssetId` and the mappings which are not stored in both directions are
+    /// skipped
+    fn old_mappings<T: Config>() -> Vec<(AssetId, MultiLocation)> {
+        let pallet = <Pallet<T> as PalletInfoAccess>::name().as_bytes();
+        storage_iter::<AssetId>(pallet, b"MultilocationToAssetId")
+            .filter_map(|(_, asset_id)| {
+                let location = old::AssetIdToMultilocation::<T>::get(asset_id)?;
+                (old::MultilocationToAssetId::<T>::get(location) == Some(asset_id))
+                    .then_some((asset_id, location))
+            })
+            .collect()
+    }
+
+    /// Moves the asset mappings to `Blake2_128Concat` keys, so that they can be iterated
+    pub struct MigrateToV1<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let db_weight = T::DbWeight::get();
+            if StorageVersion::get::<Pallet<T>>() >= 1 {
+                log::info!(target: "runtime::xcm_app", "MigrateToV1 is already applied");
+                return db_weight.reads(1);
+            }
+            let mappings = old_mappings::<T>();
+            // The maps keep their prefixes, so the old entries are removed before the new
+            // ones are written
+            let pallet = <Pallet<T> as PalletInfoAccess>::name().as_bytes();
+            let removed = clear_storage_prefix(pallet, b"AssetIdToMultilocation", b"", None, None)
+                .unique
+                + clear_storage_prefix(pallet, b"MultilocationToAssetId", b"", None, None).unique;
+            for (asset_id, location) in &mappings {
+                AssetIdToMultilocation::<T>::insert(asset_id, location);
+                MultilocationToAssetId::<T>::insert(location, asset_id);
+            }
+            StorageVersion::new(1).put::<Pallet<T>>();
+            log::info!(target: "runtime::xcm_app", "Migrated {} mappings to v1", mappings.len());
+            let mappings_len = mappings.len() as u64;
+            db_weight.reads_writes(1 + 3 * mappings_len, 1 + removed as u64 + 2 * mappings_len)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+            // Nothing to check if the migration is already applied
+            let mappings = (StorageVersion::get::<Pallet<T>>() < 1).then(old_mappings::<T>);
+            Ok(mappings.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+            let mappings = Option::<Vec<(AssetId, MultiLocation)>>::decode(&mut &state[..])
+                .map_err(|_| "Failed to decode the mappings")?;
+            ensure!(StorageVersion::get::<Pallet<T>>() == 1, "Storage version is not updated");
+            let mappings = match mappings {
+                Some(mappings) => mappings,
+                None => return Ok(()),
+            };
+            ensure!(
+                AssetIdToMultilocation::<T>::iter().count() == mappings.len()
+                    && MultilocationToAssetId::<T>::iter().count() == mappings.len(),
+                "Number of the mappings has changed"
+            );
+            for (asset_id, location) in mappings {
+                ensure!(
+                    AssetIdToMultilocation::<T>::get(asset_id) == Some(location)
+                        && MultilocationToAssetId::<T>::get(location) == Some(asset_id),
+                    "Mapping is not migrated"
+                );
+            }
+            Ok(())
+        }
+    }
+}
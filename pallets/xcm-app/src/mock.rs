@@ -30,9 +30,13 @@
 
 use crate as xcm_app;
 use bridge_types::{traits::OutboundChannel, SubNetworkId};
-use frame_support::{parameter_types, traits::Everything};
+use frame_support::{
+    parameter_types,
+    traits::{EqualPrivilegeOnly, Everything},
+    weights::Weight,
+};
 use frame_system as system;
-use orml_traits::XcmTransfer;
+use orml_traits::{parameter_type_with_key, MultiCurrency, XcmTransfer};
 use parachain_common::primitives::AssetId;
 use sp_core::H256;
 use sp_runtime::{
@@ -47,6 +51,9 @@ type Block = frame_system::mocking::MockBlock<Test>;
 type AccountId = u128;
 type Balance = u128;
 
+pub const SELF_PARA_ID: u32 = 2000;
+pub const SIBLING_PARA_ID: u32 = 2001;
+
 // Configure a mock runtime to test the pallet.
 frame_support::construct_runtime!(
     pub enum Test where
@@ -55,6 +62,10 @@ frame_support::construct_runtime!(
         UncheckedExtrinsic = UncheckedExtrinsic,
     {
         System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+        Balances: pallet_balances::{Pallet, Call, Storage, Event<T>},
+        Preimage: pallet_preimage::{Pallet, Call, Storage, Event<T>},
+        Scheduler: pallet_scheduler::{Pallet, Call, Storage, Event<T>},
+        Tokens: orml_tokens::{Pallet, Storage, Event<T>},
         XCMApp: xcm_app::{Pallet, Call, Storage, Event<T>},
     }
 );
@@ -62,6 +73,23 @@ frame_support::construct_runtime!(
 parameter_types! {
     pub const BlockHashCount: u64 = 250;
     pub const SS58Prefix: u8 = 42;
+    pub static CallOriginNetwork: SubNetworkId = SubNetworkId::Mainnet;
+    pub static LastSubmittedNetwork: Option<SubNetworkId> = None;
+    pub static LastSubmittedPayload: Vec<u8> = vec![];
+    pub static SentXcm: Vec<(MultiLocation, Xcm<()>)> = vec![];
+    /// Error `TestXcmTransfer::transfer` fails with after the asset is withdrawn
+    pub static XcmTransferError: Option<sp_runtime::DispatchError> = None;
+    /// Queries registered by `TestTransactQueryHandler`, [(Responder, Timeout, Querier)]
+    pub static TransactQueries: Vec<(MultiLocation, u64, MultiLocation)> = vec![];
+    pub static Responder: MultiLocation = MultiLocation::new(1, X1(Parachain(SIBLING_PARA_ID)));
+    pub UniversalLocation: InteriorMultiLocation =
+        X2(GlobalConsensus(NetworkId::Rococo), Parachain(SELF_PARA_ID));
+    pub const MaxTransactWeight: Weight = Weight::from_ref_time(1_000_000_000);
+    pub const MaxTransactCallLength: u32 = 64;
+    pub const TransactReportTimeout: u64 = 100;
+    pub const ExistentialDeposit: Balance = 1;
+    pub const MaximumSchedulerWeight: Weight = Weight::MAX;
+    pub const ChangeDelay: u64 = 10;
 }
 
 impl system::Config for Test {
@@ -82,7 +110,7 @@ impl system::Config for Test {
     type BlockHashCount = BlockHashCount;
     type Version = ();
     type PalletInfo = PalletInfo;
-    type AccountData = ();
+    type AccountData = pallet_balances::AccountData<Balance>;
     type OnNewAccount = ();
     type OnKilledAccount = ();
     type SystemWeightInfo = ();
@@ -96,11 +124,80 @@ impl xcm_app::Config for Test {
     type WeightInfo = ();
     type Balance = Balance;
     type OutboundChannel = TestOutboundChannel;
+    type ManagerOrigin = frame_system::EnsureRoot<AccountId>;
     type AccountIdToMultiLocation = TestAccountIdToMultiLocation;
     type XcmTransfer = TestXcmTransfer;
     type CallOrigin = TestCallOrigin;
     type AccountIdConverter = TestAccountIdConverter;
     type BalanceConverter = ();
+    type RuntimeCall = RuntimeCall;
+    type PalletsOrigin = OriginCaller;
+    type Scheduler = Scheduler;
+    type Preimages = Preimage;
+    type ChangeDelay = ChangeDelay;
+    type CancelOrigin = frame_system::EnsureRoot<AccountId>;
+    type LocalAssets = Tokens;
+    type XcmSender = TestXcmSender;
+    type UniversalLocation = UniversalLocation;
+    type TransactQueryHandler = TestTransactQueryHandler;
+    type ResponseOrigin = TestResponseOrigin;
+    type MaxTransactWeight = MaxTransactWeight;
+    type MaxTransactCallLength = MaxTransactCallLength;
+    type TransactReportTimeout = TransactReportTimeout;
+}
+
+parameter_type_with_key! {
+    pub ExistentialDeposits: |_currency_id: AssetId| -> Balance {
+        Default::default()
+    };
+}
+
+impl orml_tokens::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = Balance;
+    type Amount = i128;
+    type CurrencyId = AssetId;
+    type WeightInfo = ();
+    type ExistentialDeposits = ExistentialDeposits;
+    type CurrencyHooks = ();
+    type MaxLocks = frame_support::traits::ConstU32<50>;
+    type MaxReserves = frame_support::traits::ConstU32<50>;
+    type ReserveIdentifier = [u8; 8];
+    type DustRemovalWhitelist = Everything;
+}
+
+impl pallet_balances::Config for Test {
+    type Balance = Balance;
+    type DustRemoval = ();
+    type RuntimeEvent = RuntimeEvent;
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type MaxLocks = ();
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+}
+
+impl pallet_preimage::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
+    type Currency = Balances;
+    type ManagerOrigin = frame_system::EnsureRoot<AccountId>;
+    type BaseDeposit = ();
+    type ByteDeposit = ();
+}
+
+impl pallet_scheduler::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeOrigin = RuntimeOrigin;
+    type PalletsOrigin = OriginCaller;
+    type RuntimeCall = RuntimeCall;
+    type MaximumWeight = MaximumSchedulerWeight;
+    type ScheduleOrigin = frame_system::EnsureRoot<AccountId>;
+    type MaxScheduledPerBlock = frame_support::traits::ConstU32<50>;
+    type WeightInfo = ();
+    type OriginPrivilegeCmp = EqualPrivilegeOnly;
+    type Preimages = Preimage;
 }
 
 pub struct TestAccountIdConverter;
@@ -113,7 +210,29 @@ impl sp_runtime::traits::Convert<AccountId, sp_runtime::AccountId32> for TestAcc
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-    system::GenesisConfig::default().build_storage::<Test>().unwrap().into()
+    let mut t = system::GenesisConfig::default().build_storage::<Test>().unwrap();
+    let xcm_app_config = xcm_app::GenesisConfig {
+        allowed_destinations: vec![
+            MultiLocation::parent(),
+            MultiLocation::new(1, X1(Parachain(SIBLING_PARA_ID))),
+        ],
+        ..Default::default()
+    };
+    <xcm_app::GenesisConfig as frame_support::traits::GenesisBuild<Test>>::assimilate_storage(
+        &xcm_app_config,
+        &mut t,
+    )
+    .unwrap();
+    t.into()
+}
+
+/// Move to the given block, dispatching the scheduled calls on the way
+pub fn run_to_block(n: u64) {
+    use frame_support::traits::OnInitialize;
+    while System::block_number() < n {
+        System::set_block_number(System::block_number() + 1);
+        Scheduler::on_initialize(System::block_number());
+    }
 }
 
 pub fn test_general_key() -> [u8; 32] {
@@ -123,11 +242,13 @@ pub fn test_general_key() -> [u8; 32] {
 pub struct TestOutboundChannel;
 impl OutboundChannel<SubNetworkId, AccountId, ()> for TestOutboundChannel {
     fn submit(
-        _network_id: SubNetworkId,
+        network_id: SubNetworkId,
         _who: &system::RawOrigin<AccountId>,
-        _payload: &[u8],
+        payload: &[u8],
         _additional: (),
     ) -> Result<H256, sp_runtime::DispatchError> {
+        LastSubmittedNetwork::set(Some(network_id));
+        LastSubmittedPayload::set(payload.to_vec());
         Ok([
             1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
             1, 1, 1,
@@ -144,7 +265,7 @@ impl sp_runtime::traits::Convert<AccountId, MultiLocation> for TestAccountIdToMu
             .concat()
             .try_into()
             .expect("Failed to convert account if to xcm multilocaton");
-        X1(AccountId32 { network: Some(xcm::v3::NetworkId::Rococo), id: arrarr.into() }).into()
+        X1(AccountId32 { network: Some(XCMApp::relay_network()), id: arrarr.into() }).into()
     }
 }
 
@@ -217,11 +338,16 @@ impl XcmTransfer<AccountId, Balance, AssetId> for TestXcmTransfer {
 
     fn transfer(
         sender: AccountId,
-        _currency_id: AssetId,
-        _amount: Balance,
+        currency_id: AssetId,
+        amount: Balance,
         dest: MultiLocation,
         _dest_weight_limit: WeightLimit,
     ) -> Result<orml_traits::xcm_transfer::Transferred<AccountId>, sp_runtime::DispatchError> {
+        // XTokens withdraws the asset from the sender with the local asset transactor
+        XCMApp::withdraw(currency_id, &sender, amount)?;
+        if let Some(e) = XcmTransferError::get() {
+            return Err(e);
+        }
         Ok(orml_traits::xcm_transfer::Transferred {
             sender,
             dest,
@@ -232,12 +358,12 @@ impl XcmTransfer<AccountId, Balance, AssetId> for TestXcmTransfer {
 }
 
 pub struct TestCallOrigin;
-impl<OuterOrigin> frame_support::traits::EnsureOrigin<OuterOrigin> for TestCallOrigin {
+impl frame_support::traits::EnsureOrigin<RuntimeOrigin> for TestCallOrigin {
     type Success = bridge_types::types::CallOriginOutput<SubNetworkId, H256, ()>;
 
-    fn try_origin(_o: OuterOrigin) -> Result<Self::Success, OuterOrigin> {
+    fn try_origin(_o: RuntimeOrigin) -> Result<Self::Success, RuntimeOrigin> {
         Ok(bridge_types::types::CallOriginOutput {
-            network_id: SubNetworkId::Mainnet,
+            network_id: CallOriginNetwork::get(),
             message_id: [
                 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
                 1, 1, 1, 1,
@@ -247,4 +373,64 @@ impl<OuterOrigin> frame_support::traits::EnsureOrigin<OuterOrigin> for TestCallO
             additional: (),
         })
     }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+        Ok(RuntimeOrigin::root())
+    }
+}
+
+/// Records the sent messages in `SentXcm`
+pub struct TestXcmSender;
+impl SendXcm for TestXcmSender {
+    type Ticket = (MultiLocation, Xcm<()>);
+
+    fn validate(
+        dest: &mut Option<MultiLocation>,
+        msg: &mut Option<Xcm<()>>,
+    ) -> SendResult<Self::Ticket> {
+        let ticket = (dest.take().ok_or(SendError::MissingArgument)?, msg.take().unwrap());
+        Ok((ticket, MultiAssets::new()))
+    }
+
+    fn deliver(ticket: Self::Ticket) -> Result<XcmHash, SendError> {
+        let mut sent = SentXcm::get();
+        sent.push(ticket);
+        SentXcm::set(sent);
+        Ok([0; 32])
+    }
+}
+
+/// Records the registered queries in `TransactQueries`, the index is used as the query id
+pub struct TestTransactQueryHandler;
+impl xcm_app::TransactQueryHandler<RuntimeCall, u64> for TestTransactQueryHandler {
+    fn new_notify_query(
+        responder: MultiLocation,
+        _notify: RuntimeCall,
+        timeout: u64,
+        querier: MultiLocation,
+    ) -> xcm::v3::QueryId {
+        let mut queries = TransactQueries::get();
+        queries.push((responder, timeout, querier));
+        TransactQueries::set(queries.clone());
+        queries.len() as xcm::v3::QueryId - 1
+    }
+}
+
+/// Root acts as the query response sent by `Responder`
+pub struct TestResponseOrigin;
+impl frame_support::traits::EnsureOrigin<RuntimeOrigin> for TestResponseOrigin {
+    type Success = MultiLocation;
+
+    fn try_origin(o: RuntimeOrigin) -> Result<Self::Success, RuntimeOrigin> {
+        <frame_system::EnsureRoot<AccountId> as frame_support::traits::EnsureOrigin<
+            RuntimeOrigin,
+        >>::try_origin(o)
+        .map(|_| Responder::get())
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+        Ok(RuntimeOrigin::root())
+    }
 }
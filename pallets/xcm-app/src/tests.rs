@@ -28,15 +28,38 @@
 // STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use crate::{mock::*, Error};
-use bridge_types::{types::AssetKind, H256};
-use frame_support::{assert_noop, assert_ok};
+use crate::{
+    mock::*, parse_sora_account_location, sora_account_location, AssetPolicy, BeneficiaryKind,
+    DelayedChange, Error, Event, TransactReport, WeightInfo,
+};
+use bridge_types::{
+    substrate::{SubstrateAppCall, SubstrateBridgeMessageEncode},
+    types::AssetKind,
+    SubNetworkId, H256,
+};
+use codec::Decode;
+use frame_support::{
+    assert_noop, assert_ok, dispatch::DispatchResultWithPostInfo, traits::Contains, weights::Weight,
+};
+use orml_traits::MultiCurrency;
+use parachain_common::bridge::ParachainBridgeCall;
+use sp_runtime::{traits::Convert, DispatchError};
 use xcm::{
     opaque::latest::{
-        Junction::{GeneralKey, Parachain},
-        Junctions::X2,
+        Junction::{AccountId32, AccountKey20, GeneralIndex, GeneralKey, Parachain},
+        Junctions::{X1, X2},
+    },
+    v3::{
+        Instruction::{
+            BuyExecution, DepositAsset, DescendOrigin, RefundSurplus, ReportError, SetAppendix,
+            Transact, WithdrawAsset,
+        },
+        MultiAsset,
+        MultiAssetFilter::Wild,
+        MultiLocation, NetworkId, OriginKind, QueryResponseInfo, Response, WeightLimit,
+        WildMultiAsset::AllCounted,
+        Xcm,
     },
-    v3::MultiLocation,
 };
 
 #[test]
@@ -250,3 +273,807 @@ fn it_works_register_asset() {
         );
     });
 }
+
+#[test]
+fn it_works_set_relay_network() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(XCMApp::relay_network(), NetworkId::Rococo);
+        for network in [NetworkId::Kusama, NetworkId::Polkadot, NetworkId::Rococo] {
+            assert_ok!(XCMApp::set_relay_network(RuntimeOrigin::root(), network));
+            assert_eq!(XCMApp::relay_network(), network);
+            let location = TestAccountIdToMultiLocation::convert(1);
+            assert!(matches!(
+                location,
+                MultiLocation { parents: 0, interior: X1(AccountId32 { network: Some(n), .. }) }
+                    if n == network
+            ));
+        }
+    });
+}
+
+#[test]
+fn it_fails_set_relay_network_not_root() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            XCMApp::set_relay_network(RuntimeOrigin::signed(1), NetworkId::Kusama),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_eq!(XCMApp::relay_network(), NetworkId::Rococo);
+    });
+}
+
+#[test]
+fn it_works_register_asset_answers_on_origin_network() {
+    new_test_ext().execute_with(|| {
+        let multiasset = MultiLocation {
+            parents: 1,
+            interior: X2(Parachain(666), GeneralKey { length: 6, data: test_general_key() }),
+        };
+        CallOriginNetwork::set(SubNetworkId::Rococo);
+        assert_ok!(XCMApp::register_asset(
+            RuntimeOrigin::root(),
+            [1; 32].into(),
+            multiasset.into(),
+            AssetKind::Sidechain,
+        ));
+        assert_eq!(LastSubmittedNetwork::get(), Some(SubNetworkId::Rococo));
+        CallOriginNetwork::set(SubNetworkId::Mainnet);
+    });
+}
+
+#[test]
+fn it_works_set_sora_network() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(XCMApp::sora_network(), SubNetworkId::Mainnet);
+        assert_ok!(XCMApp::add_to_channel(1, [1; 32].into(), 10));
+        assert_eq!(LastSubmittedNetwork::get(), Some(SubNetworkId::Mainnet));
+
+        assert_ok!(XCMApp::set_sora_network(RuntimeOrigin::root(), SubNetworkId::Kusama));
+        assert_eq!(XCMApp::sora_network(), SubNetworkId::Kusama);
+        assert_ok!(XCMApp::add_to_channel(1, [1; 32].into(), 10));
+        assert_eq!(LastSubmittedNetwork::get(), Some(SubNetworkId::Kusama));
+    });
+}
+
+#[test]
+fn it_fails_set_sora_network_not_root() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            XCMApp::set_sora_network(RuntimeOrigin::signed(1), SubNetworkId::Kusama),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_eq!(XCMApp::sora_network(), SubNetworkId::Mainnet);
+    });
+}
+
+#[test]
+fn it_works_announced_change_applied_after_delay() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1);
+        let asset_id = [1; 32].into();
+        let multilocation = MultiLocation::parent();
+        let new_multilocation = MultiLocation {
+            parents: 1,
+            interior: X2(Parachain(666), GeneralKey { length: 6, data: test_general_key() }),
+        };
+        assert_ok!(XCMApp::register_mapping(asset_id, multilocation));
+
+        let change = DelayedChange::ChangeAssetMapping { asset_id, new_multilocation };
+        assert_ok!(XCMApp::announce_change(RuntimeOrigin::root(), change.clone()));
+        System::assert_last_event(Event::<Test>::ChangeAnnounced(0, change, 11).into());
+        assert!(XCMApp::pending_change(0).is_some());
+
+        run_to_block(10);
+        assert_eq!(XCMApp::get_multilocation_from_asset_id(asset_id), Some(multilocation));
+
+        run_to_block(11);
+        assert_eq!(XCMApp::get_multilocation_from_asset_id(asset_id), Some(new_multilocation));
+        assert_eq!(XCMApp::get_asset_id_from_multilocation(multilocation), None);
+        assert!(XCMApp::pending_change(0).is_none());
+        System::assert_has_event(Event::<Test>::ChangeApplied(0, Ok(())).into());
+    });
+}
+
+#[test]
+fn it_works_cancel_announced_change() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1);
+        let asset_id = [1; 32].into();
+        let multilocation = MultiLocation::parent();
+        assert_ok!(XCMApp::register_mapping(asset_id, multilocation));
+
+        assert_ok!(XCMApp::announce_change(
+            RuntimeOrigin::root(),
+            DelayedChange::DeleteMapping { asset_id }
+        ));
+        run_to_block(5);
+        assert_ok!(XCMApp::cancel_change(RuntimeOrigin::root(), 0));
+        System::assert_last_event(Event::<Test>::ChangeCancelled(0).into());
+        assert!(XCMApp::pending_change(0).is_none());
+
+        run_to_block(20);
+        assert_eq!(XCMApp::get_multilocation_from_asset_id(asset_id), Some(multilocation));
+        assert_noop!(
+            XCMApp::cancel_change(RuntimeOrigin::root(), 0),
+            Error::<Test>::ChangeNotFound
+        );
+    });
+}
+
+#[test]
+fn it_fails_announce_and_cancel_change_not_root() {
+    new_test_ext().execute_with(|| {
+        let change = DelayedChange::DeleteMapping { asset_id: [1; 32].into() };
+        assert_noop!(
+            XCMApp::announce_change(RuntimeOrigin::signed(1), change.clone()),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(XCMApp::announce_change(RuntimeOrigin::root(), change));
+        assert_noop!(
+            XCMApp::cancel_change(RuntimeOrigin::signed(1), 0),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            XCMApp::apply_change(RuntimeOrigin::signed(1), 0),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn it_works_failed_change_is_reported() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1);
+        assert_ok!(XCMApp::announce_change(
+            RuntimeOrigin::root(),
+            DelayedChange::DeleteMapping { asset_id: [1; 32].into() }
+        ));
+        run_to_block(11);
+        assert!(XCMApp::pending_change(0).is_none());
+        System::assert_has_event(
+            Event::<Test>::ChangeApplied(0, Err(Error::<Test>::MappingNotExist.into())).into(),
+        );
+    });
+}
+
+fn relay_account() -> MultiLocation {
+    MultiLocation::new(1, X1(AccountId32 { network: None, id: [5; 32] }))
+}
+
+#[test]
+fn it_works_transfer_paused_or_over_limit_refunded() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1);
+        let asset_id = [1; 32].into();
+        let recipient = xcm::VersionedMultiLocation::V3(relay_account());
+        assert_ok!(XCMApp::register_mapping(asset_id, MultiLocation::parent()));
+
+        assert_ok!(XCMApp::announce_change(
+            RuntimeOrigin::root(),
+            DelayedChange::SetAssetPaused { asset_id, paused: true }
+        ));
+        assert_ok!(XCMApp::announce_change(
+            RuntimeOrigin::root(),
+            DelayedChange::SetTransferLimit { asset_id, limit: Some(100) }
+        ));
+        assert_ok!(XCMApp::transfer(RuntimeOrigin::root(), asset_id, 1, recipient.clone(), 1000));
+
+        run_to_block(11);
+        assert!(XCMApp::is_asset_paused(asset_id));
+        assert_eq!(XCMApp::transfer_limit(asset_id), Some(100));
+        assert_ok!(XCMApp::transfer(RuntimeOrigin::root(), asset_id, 1, recipient.clone(), 10));
+        assert_refunded(asset_id, 10, Error::<Test>::AssetPaused);
+
+        assert_ok!(XCMApp::announce_change(
+            RuntimeOrigin::root(),
+            DelayedChange::SetAssetPaused { asset_id, paused: false }
+        ));
+        run_to_block(21);
+        assert!(!XCMApp::is_asset_paused(asset_id));
+        assert_ok!(XCMApp::transfer(RuntimeOrigin::root(), asset_id, 1, recipient.clone(), 100));
+        assert_ok!(XCMApp::transfer(RuntimeOrigin::root(), asset_id, 1, recipient, 101));
+        assert_refunded(asset_id, 101, Error::<Test>::TransferLimitExceeded);
+    });
+}
+
+#[test]
+fn it_works_transfer_xcm_error_refunded() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1);
+        let asset_id = [1; 32].into();
+        let recipient = xcm::VersionedMultiLocation::V3(relay_account());
+        let error = DispatchError::Other("XCM transfer failed");
+        assert_ok!(XCMApp::register_mapping(asset_id, MultiLocation::parent()));
+        hold_locally(asset_id);
+        XcmTransferError::set(Some(error));
+
+        assert_ok!(XCMApp::transfer(RuntimeOrigin::root(), asset_id, 1, recipient, 10));
+        // Nothing is minted on the parachain, the asset goes back to SORA
+        assert_eq!(Tokens::total_issuance(asset_id), 0);
+        System::assert_last_event(
+            Event::<Test>::AssetRefunded(TestAccountIdConverter::convert(1), asset_id, 10, error)
+                .into(),
+        );
+        System::assert_has_event(Event::<Test>::TrasferringAssetError(error, asset_id).into());
+    });
+}
+
+fn hold_locally(asset_id: H256) {
+    assert_ok!(XCMApp::do_apply_change(DelayedChange::SetAssetPolicy {
+        asset_id,
+        policy: AssetPolicy::HoldLocally
+    }));
+}
+
+#[test]
+fn it_works_hold_locally_routes_to_local_ledger() {
+    new_test_ext().execute_with(|| {
+        let asset_id = [1; 32].into();
+        assert_noop!(
+            <XCMApp as MultiCurrency<_>>::transfer(asset_id, &1, &2, 10),
+            Error::<Test>::MethodNotAvailible
+        );
+
+        hold_locally(asset_id);
+        System::assert_last_event(
+            Event::<Test>::AssetPolicyChanged(asset_id, AssetPolicy::HoldLocally).into(),
+        );
+        assert_ok!(XCMApp::deposit(asset_id, &1, 100));
+        assert_eq!(LastSubmittedNetwork::get(), None);
+        assert_eq!(Tokens::free_balance(asset_id, &1), 100);
+
+        assert_ok!(<XCMApp as MultiCurrency<_>>::transfer(asset_id, &1, &2, 10));
+        assert_eq!(XCMApp::free_balance(asset_id, &1), 90);
+        assert_eq!(XCMApp::free_balance(asset_id, &2), 10);
+        assert_eq!(XCMApp::total_issuance(asset_id), 100);
+    });
+}
+
+#[test]
+fn it_works_send_held_asset_over_limit() {
+    new_test_ext().execute_with(|| {
+        let asset_id = [1; 32].into();
+        let recipient = xcm::VersionedMultiLocation::V3(relay_account());
+        assert_ok!(XCMApp::register_mapping(asset_id, MultiLocation::parent()));
+        hold_locally(asset_id);
+        assert_ok!(XCMApp::deposit(asset_id, &1, 100));
+        assert_ok!(XCMApp::do_apply_change(DelayedChange::SetTransferLimit {
+            asset_id,
+            limit: Some(10)
+        }));
+
+        assert_noop!(
+            XCMApp::send_to_sora(RuntimeOrigin::signed(1), asset_id, 11),
+            Error::<Test>::TransferLimitExceeded
+        );
+        assert_noop!(
+            XCMApp::send_to_parachain(RuntimeOrigin::signed(1), asset_id, recipient.clone(), 11),
+            Error::<Test>::TransferLimitExceeded
+        );
+        assert_ok!(XCMApp::send_to_sora(RuntimeOrigin::signed(1), asset_id, 10));
+        assert_ok!(XCMApp::send_to_parachain(RuntimeOrigin::signed(1), asset_id, recipient, 10));
+        assert_eq!(Tokens::free_balance(asset_id, &1), 80);
+    });
+}
+
+#[test]
+fn it_works_set_existential_deposit() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1);
+        let asset_id = [1; 32].into();
+        assert_eq!(XCMApp::existential_deposit(asset_id), None);
+        assert_ok!(XCMApp::do_apply_change(DelayedChange::SetExistentialDeposit {
+            asset_id,
+            amount: Some(1000)
+        }));
+        assert_eq!(XCMApp::existential_deposit(asset_id), Some(1000));
+        System::assert_last_event(
+            Event::<Test>::ExistentialDepositChanged(asset_id, Some(1000)).into(),
+        );
+        assert_ok!(XCMApp::do_apply_change(DelayedChange::SetExistentialDeposit {
+            asset_id,
+            amount: None
+        }));
+        assert_eq!(XCMApp::existential_deposit(asset_id), None);
+    });
+}
+
+#[test]
+fn it_works_send_held_asset_to_sora_and_parachain() {
+    new_test_ext().execute_with(|| {
+        let asset_id = [1; 32].into();
+        let recipient = relay_account();
+        assert_ok!(XCMApp::register_mapping(asset_id, MultiLocation::parent()));
+        hold_locally(asset_id);
+        assert_ok!(XCMApp::deposit(asset_id, &1, 100));
+
+        assert_ok!(XCMApp::send_to_sora(RuntimeOrigin::signed(1), asset_id, 30));
+        assert_eq!(Tokens::free_balance(asset_id, &1), 70);
+        assert_eq!(LastSubmittedNetwork::get(), Some(SubNetworkId::Mainnet));
+
+        assert_ok!(XCMApp::send_to_parachain(
+            RuntimeOrigin::signed(1),
+            asset_id,
+            xcm::VersionedMultiLocation::V3(recipient),
+            20
+        ));
+        assert_eq!(Tokens::free_balance(asset_id, &1), 50);
+        System::assert_last_event(
+            Event::<Test>::AssetTransferred(1, recipient, asset_id, 20).into(),
+        );
+
+        assert_noop!(
+            XCMApp::send_to_sora(RuntimeOrigin::signed(1), asset_id, 51),
+            orml_tokens::Error::<Test>::BalanceTooLow
+        );
+    });
+}
+
+#[test]
+fn it_fails_send_asset_not_held_locally() {
+    new_test_ext().execute_with(|| {
+        let asset_id = [1; 32].into();
+        assert_noop!(
+            XCMApp::send_to_sora(RuntimeOrigin::signed(1), asset_id, 10),
+            Error::<Test>::AssetNotHeldLocally
+        );
+        assert_noop!(
+            XCMApp::send_to_parachain(
+                RuntimeOrigin::signed(1),
+                asset_id,
+                xcm::VersionedMultiLocation::V3(MultiLocation::parent()),
+                10
+            ),
+            Error::<Test>::AssetNotHeldLocally
+        );
+    });
+}
+
+#[test]
+fn it_works_bridge_transfer_of_held_asset_does_not_leave_balance() {
+    new_test_ext().execute_with(|| {
+        let asset_id = [1; 32].into();
+        let recipient = xcm::VersionedMultiLocation::V3(relay_account());
+        assert_ok!(XCMApp::register_mapping(asset_id, MultiLocation::parent()));
+        hold_locally(asset_id);
+
+        assert_ok!(XCMApp::transfer(RuntimeOrigin::root(), asset_id, 1, recipient, 100));
+        assert_eq!(Tokens::free_balance(asset_id, &1), 0);
+        assert_eq!(Tokens::total_issuance(asset_id), 0);
+    });
+}
+
+#[test]
+fn it_fails_forward_to_sora_with_local_balances() {
+    new_test_ext().execute_with(|| {
+        let asset_id = [1; 32].into();
+        hold_locally(asset_id);
+        assert_ok!(XCMApp::deposit(asset_id, &1, 100));
+
+        let change = DelayedChange::SetAssetPolicy { asset_id, policy: AssetPolicy::ForwardToSora };
+        assert_noop!(XCMApp::do_apply_change(change.clone()), Error::<Test>::LocalBalancesExist);
+
+        assert_ok!(XCMApp::send_to_sora(RuntimeOrigin::signed(1), asset_id, 100));
+        assert_ok!(XCMApp::do_apply_change(change));
+        assert!(!XCMApp::is_held_locally(asset_id));
+    });
+}
+
+fn sibling() -> MultiLocation {
+    MultiLocation::new(1, X1(Parachain(SIBLING_PARA_ID)))
+}
+
+fn transact(call: Vec<u8>, require_weight_at_most: Weight) -> DispatchResultWithPostInfo {
+    XCMApp::transact(
+        RuntimeOrigin::root(),
+        1,
+        xcm::VersionedMultiLocation::V3(sibling()),
+        [1; 32].into(),
+        1000,
+        require_weight_at_most,
+        call,
+    )
+}
+
+#[test]
+fn it_works_transact_on_sibling() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1);
+        assert_ok!(XCMApp::register_mapping([1; 32].into(), sibling()));
+        assert_ok!(transact(vec![1, 2, 3], Weight::from_ref_time(1000)));
+
+        let account = sora_account(SubNetworkId::Mainnet, 1);
+        let sender =
+            AccountId32 { network: None, id: TestAccountIdConverter::convert(account).into() };
+        let self_location = MultiLocation::new(1, X1(Parachain(SELF_PARA_ID)));
+        let fee: MultiAsset = (MultiLocation::here(), 1000).into();
+        let expected = Xcm(vec![
+            DescendOrigin(X1(sender)),
+            WithdrawAsset(fee.clone().into()),
+            BuyExecution { fees: fee, weight_limit: WeightLimit::Unlimited },
+            SetAppendix(Xcm(vec![
+                RefundSurplus,
+                DepositAsset {
+                    assets: Wild(AllCounted(1)),
+                    beneficiary: MultiLocation::new(1, X2(Parachain(SELF_PARA_ID), sender)),
+                },
+                ReportError(QueryResponseInfo {
+                    destination: self_location,
+                    query_id: 0,
+                    max_weight: <() as WeightInfo>::report_transact(),
+                }),
+            ])),
+            Transact {
+                origin_kind: OriginKind::SovereignAccount,
+                require_weight_at_most: Weight::from_ref_time(1000),
+                call: vec![1, 2, 3].into(),
+            },
+        ]);
+        assert_eq!(SentXcm::get(), vec![(sibling(), expected)]);
+        assert_eq!(TransactQueries::get(), vec![(sibling(), 101, X1(sender).into())]);
+        assert_eq!(XCMApp::pending_transact(0), Some((account, sibling())));
+        System::assert_last_event(Event::<Test>::TransactSent(account, sibling(), 0).into());
+    });
+}
+
+#[test]
+fn it_fails_transact_invalid_params() {
+    new_test_ext().execute_with(|| {
+        let asset_id = [1; 32].into();
+        assert_noop!(transact(vec![], Weight::from_ref_time(1000)), Error::<Test>::MappingNotExist);
+        assert_ok!(XCMApp::register_mapping(asset_id, sibling()));
+        assert_noop!(
+            XCMApp::transact(
+                RuntimeOrigin::root(),
+                1,
+                xcm::VersionedMultiLocation::V3(MultiLocation::parent()),
+                asset_id,
+                1000,
+                Weight::from_ref_time(1000),
+                vec![],
+            ),
+            Error::<Test>::InvalidTransactDestination
+        );
+        assert_noop!(
+            transact(vec![], MaxTransactWeight::get().add_ref_time(1)),
+            Error::<Test>::TransactWeightExceeded
+        );
+        assert_noop!(
+            transact(vec![0; 65], Weight::from_ref_time(1000)),
+            Error::<Test>::TransactCallTooLong
+        );
+        assert_ok!(XCMApp::do_apply_change(DelayedChange::SetTransferLimit {
+            asset_id,
+            limit: Some(999)
+        }));
+        assert_noop!(
+            transact(vec![], Weight::from_ref_time(1000)),
+            Error::<Test>::TransferLimitExceeded
+        );
+        assert!(SentXcm::get().is_empty());
+    });
+}
+
+#[test]
+fn it_works_report_transact_to_sora() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1);
+        assert_ok!(XCMApp::register_mapping([1; 32].into(), sibling()));
+        assert_ok!(transact(vec![1, 2, 3], Weight::from_ref_time(1000)));
+
+        let error = Some((4, xcm::v3::Error::FailedToDecode));
+        assert_noop!(
+            XCMApp::report_transact(RuntimeOrigin::root(), 0, Response::Null),
+            Error::<Test>::UnexpectedTransactResponse
+        );
+        assert_ok!(XCMApp::report_transact(
+            RuntimeOrigin::root(),
+            0,
+            Response::ExecutionResult(error)
+        ));
+        // SORA decodes the payload as a bridge call
+        assert_eq!(
+            ParachainBridgeCall::decode(&mut &LastSubmittedPayload::get()[..]).unwrap(),
+            ParachainBridgeCall::XCMAppTransactReport(TransactReport {
+                sender: TestAccountIdConverter::convert(1),
+                query_id: 0,
+                error
+            })
+        );
+        assert!(XCMApp::pending_transact(0).is_none());
+        System::assert_last_event(Event::<Test>::TransactReported(0, error).into());
+
+        assert_noop!(
+            XCMApp::report_transact(RuntimeOrigin::root(), 0, Response::ExecutionResult(None)),
+            Error::<Test>::UnknownTransactQuery
+        );
+    });
+}
+
+#[test]
+fn it_fails_report_transact_from_other_responder() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(XCMApp::register_mapping([1; 32].into(), sibling()));
+        assert_ok!(transact(vec![], Weight::from_ref_time(1000)));
+
+        Responder::set(MultiLocation::new(1, X1(Parachain(SIBLING_PARA_ID + 1))));
+        assert_noop!(
+            XCMApp::report_transact(RuntimeOrigin::root(), 0, Response::ExecutionResult(None)),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            XCMApp::report_transact(RuntimeOrigin::signed(1), 0, Response::ExecutionResult(None)),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+fn sora_account(network_id: SubNetworkId, account: u128) -> u128 {
+    XCMApp::sora_account_id(network_id, TestAccountIdConverter::convert(account))
+}
+
+#[test]
+fn it_works_transfer_from_derived_sora_account() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1);
+        let asset_id = [1; 32].into();
+        let recipient = relay_account();
+        assert_ok!(XCMApp::register_mapping(asset_id, MultiLocation::parent()));
+        hold_locally(asset_id);
+
+        let mainnet_account = sora_account(SubNetworkId::Mainnet, 1);
+        let kusama_account = sora_account(SubNetworkId::Kusama, 1);
+        assert_ne!(mainnet_account, 1);
+        assert_ne!(mainnet_account, kusama_account);
+        assert_eq!(sora_account(SubNetworkId::Mainnet, 1), mainnet_account);
+
+        CallOriginNetwork::set(SubNetworkId::Kusama);
+        assert_ok!(XCMApp::transfer(
+            RuntimeOrigin::root(),
+            asset_id,
+            1,
+            xcm::VersionedMultiLocation::V3(recipient),
+            100
+        ));
+        CallOriginNetwork::set(SubNetworkId::Mainnet);
+        System::assert_last_event(
+            Event::<Test>::AssetTransferred(kusama_account, recipient, asset_id, 100).into(),
+        );
+        assert_eq!(Tokens::free_balance(asset_id, &kusama_account), 100);
+        assert_eq!(Tokens::free_balance(asset_id, &1), 0);
+        assert_eq!(
+            XCMApp::sora_account(kusama_account),
+            Some((SubNetworkId::Kusama, TestAccountIdConverter::convert(1)))
+        );
+        assert!(XCMApp::sora_account(mainnet_account).is_none());
+
+        // Assets of the derived account go back to the SORA account on its network
+        assert_ok!(XCMApp::send_to_sora(RuntimeOrigin::signed(kusama_account), asset_id, 100));
+        assert_eq!(LastSubmittedNetwork::get(), Some(SubNetworkId::Kusama));
+        System::assert_last_event(
+            Event::<Test>::AssetAddedToChannel(SubstrateAppCall::Transfer {
+                asset_id,
+                recipient: TestAccountIdConverter::convert(1),
+                sender: None,
+                amount: 100,
+            })
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn it_works_sora_account_location() {
+    let account = TestAccountIdConverter::convert(1);
+    let location = sora_account_location(SubNetworkId::Kusama, account.clone());
+    assert_eq!(parse_sora_account_location(&location), Some((SubNetworkId::Kusama, account)));
+    assert_eq!(parse_sora_account_location(&MultiLocation::parent()), None);
+    assert_eq!(
+        parse_sora_account_location(&MultiLocation::new(
+            0,
+            X2(GeneralIndex(u8::MAX as u128 + 1), AccountId32 { network: None, id: [1; 32] })
+        )),
+        None
+    );
+}
+
+/// Check that the transfer from the SORA account `1` has been sent back
+fn assert_refunded(asset_id: H256, amount: u128, error: Error<Test>) {
+    System::assert_last_event(
+        Event::<Test>::AssetRefunded(
+            TestAccountIdConverter::convert(1),
+            asset_id,
+            amount,
+            error.into(),
+        )
+        .into(),
+    );
+}
+
+#[test]
+fn it_works_account_key20_beneficiary() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1);
+        let asset_id = [1; 32].into();
+        let evm_chain = MultiLocation::new(1, X1(Parachain(SIBLING_PARA_ID)));
+        let evm_account = MultiLocation::new(
+            1,
+            X2(Parachain(SIBLING_PARA_ID), AccountKey20 { network: None, key: [7; 20] }),
+        );
+        let substrate_account = MultiLocation::new(
+            1,
+            X2(Parachain(SIBLING_PARA_ID), AccountId32 { network: None, id: [7; 32] }),
+        );
+        assert_ok!(XCMApp::register_mapping(asset_id, MultiLocation::parent()));
+        assert_ok!(XCMApp::transfer(RuntimeOrigin::root(), asset_id, 1, evm_account.into(), 10));
+        assert_refunded(asset_id, 10, Error::<Test>::InvalidBeneficiary);
+
+        assert_ok!(XCMApp::do_apply_change(DelayedChange::SetBeneficiaryKind {
+            destination: evm_chain,
+            kind: BeneficiaryKind::AccountKey20
+        }));
+        System::assert_last_event(
+            Event::<Test>::BeneficiaryKindChanged(evm_chain, BeneficiaryKind::AccountKey20).into(),
+        );
+        assert_ok!(XCMApp::transfer(RuntimeOrigin::root(), asset_id, 1, evm_account.into(), 10));
+        System::assert_last_event(
+            Event::<Test>::AssetTransferred(
+                sora_account(SubNetworkId::Mainnet, 1),
+                evm_account,
+                asset_id,
+                10,
+            )
+            .into(),
+        );
+        assert_noop!(
+            XCMApp::ensure_valid_recipient_location(&substrate_account),
+            Error::<Test>::InvalidBeneficiary
+        );
+
+        assert_ok!(XCMApp::do_apply_change(DelayedChange::SetBeneficiaryKind {
+            destination: evm_chain,
+            kind: BeneficiaryKind::AccountId32
+        }));
+        assert!(!crate::BeneficiaryKinds::<Test>::contains_key(evm_chain));
+        assert_ok!(XCMApp::ensure_valid_recipient_location(&substrate_account));
+    });
+}
+
+#[test]
+fn it_works_transfer_to_invalid_recipient_refunded() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1);
+        let asset_id = [1; 32].into();
+        assert_ok!(XCMApp::register_mapping(asset_id, MultiLocation::parent()));
+        let other_sibling = MultiLocation::new(1, X1(Parachain(SIBLING_PARA_ID + 1)));
+        let other_sibling_account = MultiLocation::new(
+            1,
+            X2(Parachain(SIBLING_PARA_ID + 1), AccountId32 { network: None, id: [7; 32] }),
+        );
+
+        for (recipient, error) in [
+            (MultiLocation::parent(), Error::<Test>::InvalidRecipient),
+            (sibling(), Error::<Test>::InvalidRecipient),
+            (
+                MultiLocation::new(2, X1(AccountId32 { network: None, id: [7; 32] })),
+                Error::<Test>::InvalidRecipient,
+            ),
+            (
+                MultiLocation::new(
+                    1,
+                    X1(AccountId32 { network: Some(NetworkId::Kusama), id: [7; 32] }),
+                ),
+                Error::<Test>::InvalidRecipient,
+            ),
+            (other_sibling_account, Error::<Test>::DestinationNotAllowed),
+        ] {
+            LastSubmittedNetwork::set(None);
+            CallOriginNetwork::set(SubNetworkId::Kusama);
+            assert_ok!(XCMApp::transfer(RuntimeOrigin::root(), asset_id, 1, recipient.into(), 10));
+            CallOriginNetwork::set(SubNetworkId::Mainnet);
+            assert_refunded(asset_id, 10, error);
+            // Sent back to the network the transfer came from
+            assert_eq!(LastSubmittedNetwork::get(), Some(SubNetworkId::Kusama));
+            assert_eq!(
+                LastSubmittedPayload::get(),
+                SubstrateAppCall::Transfer {
+                    asset_id,
+                    recipient: TestAccountIdConverter::convert(1),
+                    sender: None,
+                    amount: 10,
+                }
+                .prepare_message()
+            );
+        }
+        assert!(XCMApp::sora_account(sora_account(SubNetworkId::Kusama, 1)).is_none());
+
+        assert_noop!(
+            XCMApp::do_apply_change(DelayedChange::SetDestinationAllowed {
+                destination: other_sibling_account,
+                allowed: true
+            }),
+            Error::<Test>::InvalidDestination
+        );
+        assert_ok!(XCMApp::do_apply_change(DelayedChange::SetDestinationAllowed {
+            destination: other_sibling,
+            allowed: true
+        }));
+        System::assert_last_event(
+            Event::<Test>::DestinationAllowanceChanged(other_sibling, true).into(),
+        );
+        assert_ok!(XCMApp::transfer(
+            RuntimeOrigin::root(),
+            asset_id,
+            1,
+            other_sibling_account.into(),
+            10
+        ));
+        System::assert_last_event(
+            Event::<Test>::AssetTransferred(
+                sora_account(SubNetworkId::Mainnet, 1),
+                other_sibling_account,
+                asset_id,
+                10,
+            )
+            .into(),
+        );
+
+        assert_ok!(XCMApp::do_apply_change(DelayedChange::SetDestinationAllowed {
+            destination: other_sibling,
+            allowed: false
+        }));
+        assert!(!crate::AllowedDestinations::<Test>::contains_key(other_sibling));
+        assert!(!crate::AllowedRecipients::<Test>::contains(&other_sibling_account));
+        assert!(crate::AllowedRecipients::<Test>::contains(&relay_account()));
+    });
+}
+
+#[test]
+fn it_works_migrate_to_v1() {
+    new_test_ext().execute_with(|| {
+        use crate::migrations::v1::{old, MigrateToV1};
+        use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+
+        let relay_asset_id = [1; 32].into();
+        let sibling_asset_id = [2; 32].into();
+        let sibling_asset = MultiLocation::new(
+            1,
+            X2(Parachain(SIBLING_PARA_ID + 1), GeneralKey { length: 32, data: test_general_key() }),
+        );
+        for (asset_id, location) in
+            [(relay_asset_id, MultiLocation::parent()), (sibling_asset_id, sibling_asset)]
+        {
+            old::AssetIdToMultilocation::<Test>::insert(asset_id, location);
+            old::MultilocationToAssetId::<Test>::insert(location, asset_id);
+        }
+        // Mapping stored only in one direction is dropped
+        let broken_location = MultiLocation::new(1, X1(Parachain(SIBLING_PARA_ID + 2)));
+        old::MultilocationToAssetId::<Test>::insert(broken_location, H256::from([3; 32]));
+        let _ = crate::AllowedDestinations::<Test>::clear(u32::MAX, None);
+        crate::PausedAssets::<Test>::insert(relay_asset_id, true);
+        StorageVersion::new(0).put::<XCMApp>();
+
+        MigrateToV1::<Test>::on_runtime_upgrade();
+        assert_eq!(StorageVersion::get::<XCMApp>(), 1);
+        assert_eq!(
+            XCMApp::get_multilocation_from_asset_id(relay_asset_id),
+            Some(MultiLocation::parent())
+        );
+        assert_eq!(XCMApp::get_multilocation_from_asset_id(sibling_asset_id), Some(sibling_asset));
+        assert_eq!(XCMApp::get_asset_id_from_multilocation(sibling_asset), Some(sibling_asset_id));
+        assert_eq!(XCMApp::get_asset_id_from_multilocation(broken_location), None);
+        assert_eq!(crate::AssetIdToMultilocation::<Test>::iter().count(), 2);
+        assert_eq!(crate::MultilocationToAssetId::<Test>::iter().count(), 2);
+        // Other maps are left as they are, no destination is allowed by the migration
+        assert!(XCMApp::is_asset_paused(relay_asset_id));
+        assert_eq!(crate::AllowedDestinations::<Test>::iter().count(), 0);
+
+        // Applied only once
+        let new_asset_id = H256::from([4; 32]);
+        old::AssetIdToMultilocation::<Test>::insert(new_asset_id, broken_location);
+        old::MultilocationToAssetId::<Test>::insert(broken_location, new_asset_id);
+        MigrateToV1::<Test>::on_runtime_upgrade();
+        assert_eq!(XCMApp::get_multilocation_from_asset_id(new_asset_id), None);
+        assert_eq!(crate::AssetIdToMultilocation::<Test>::iter().count(), 2);
+    });
+}
@@ -1,28 +1,29 @@
 
-//! Autogenerated weights for `pallet_converter`
+//! Weights for `xcm_app`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2022-09-20, STEPS: `5`, REPEAT: 1, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+//! NOT GENERATED: the values are estimated by hand from the storage accesses of the calls
+//! and have not been measured. Regenerate them on the reference hardware with
+//! `./housekeeping/benchmark.sh xcm_app` before relying on them.
 
-// Executed Command:
+// Command to generate:
 // ./target/release/parachain-collator
 // benchmark
 // pallet
+// --template=./housekeeping/pallet-weight-template.hbs
 // --chain
 // dev
 // --execution=wasm
 // --wasm-execution=compiled
 // --pallet
-// pallet_converter
+// xcm_app
 // --extrinsic
 // *
 // --steps
-// 5
+// 50
 // --repeat
-// 1
+// 20
 // --output
-// pallets/converter/src/weights.rs
+// pallets/xcm-app/src/weights.rs
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -32,52 +33,156 @@ use frame_support::{traits::Get, weights::Weight};
 use core::marker::PhantomData;
 use parachain_common::primitives::EXTRINSIC_FIXED_WEIGHT;
 
-/// Weight functions for `pallet_converter`.
+/// Weight functions for `xcm_app`.
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
-	// Storage: Converter AssetIdToMultilocation (r:1 w:1)
-	// Storage: Converter MultilocationToAssetId (r:0 w:1)
+	// Storage: XCMApp AssetIdToMultilocation (r:1 w:1)
+	// Storage: XCMApp MultilocationToAssetId (r:1 w:1)
 	fn register_mapping() -> Weight {
-		Weight::from_ref_time(25_000_000)
-			.saturating_add(T::DbWeight::get().reads(1))
+		Weight::from_ref_time(24_614_000)
+			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
-	// Storage: Converter AssetIdToMultilocation (r:1 w:1)
-	// Storage: Converter MultilocationToAssetId (r:1 w:2)
+	// Storage: XCMApp AssetIdToMultilocation (r:1 w:1)
+	// Storage: XCMApp MultilocationToAssetId (r:1 w:2)
 	fn change_asset_mapping() -> Weight {
-		Weight::from_ref_time(22_000_000)
+		Weight::from_ref_time(29_873_000)
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
-	// Storage: Converter MultilocationToAssetId (r:1 w:1)
-	// Storage: Converter AssetIdToMultilocation (r:1 w:2)
+	// Storage: XCMApp MultilocationToAssetId (r:1 w:1)
+	// Storage: XCMApp AssetIdToMultilocation (r:1 w:2)
 	fn change_multilocation_mapping() -> Weight {
-		Weight::from_ref_time(21_000_000)
+		Weight::from_ref_time(29_551_000)
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
-	// Storage: Converter AssetIdToMultilocation (r:1 w:1)
-	// Storage: Converter MultilocationToAssetId (r:0 w:1)
+	// Storage: XCMApp AssetIdToMultilocation (r:1 w:1)
+	// Storage: XCMApp MultilocationToAssetId (r:0 w:1)
 	fn delete_mapping() -> Weight {
-		Weight::from_ref_time(17_000_000)
+		Weight::from_ref_time(21_307_000)
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
-
+	// Storage: XCMApp AllowedDestinations (r:1 w:0)
+	// Storage: XCMApp BeneficiaryKinds (r:1 w:0)
+	// Storage: XCMApp AssetIdToMultilocation (r:1 w:0)
+	// Storage: ParachainInfo ParachainId (r:1 w:0)
+	// Storage: PolkadotXcm SupportedVersion (r:1 w:0)
+	// Storage: PolkadotXcm VersionDiscoveryQueue (r:1 w:1)
+	// Storage: PolkadotXcm SafeXcmVersion (r:1 w:0)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	// Storage: XCMApp SoraAccounts (r:0 w:1)
 	fn transfer() -> Weight {
-		Weight::from_ref_time(17_000_000)
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(2))
-    }
-
+		Weight::from_ref_time(127_816_000)
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: XCMApp AssetIdToMultilocation (r:1 w:1)
+	// Storage: XCMApp MultilocationToAssetId (r:1 w:1)
+	// Storage: SubstrateBridgeOutboundChannel MessageQueues (r:1 w:1)
+	// Storage: SubstrateBridgeOutboundChannel ChannelNonces (r:1 w:0)
+	// Storage: SubstrateBridgeOutboundChannel Interval (r:1 w:0)
 	fn register_asset() -> Weight {
-		Weight::from_ref_time(17_000_000)
-			.saturating_add(T::DbWeight::get().reads(1))
+		Weight::from_ref_time(58_436_000)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: XCMApp RelayNetwork (r:0 w:1)
+	fn set_relay_network() -> Weight {
+		Weight::from_ref_time(12_410_000)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: XCMApp SoraNetwork (r:0 w:1)
+	fn set_sora_network() -> Weight {
+		Weight::from_ref_time(12_187_000)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: XCMApp NextChangeId (r:1 w:1)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:1 w:1)
+	// Storage: XCMApp PendingChanges (r:0 w:1)
+	fn announce_change() -> Weight {
+		Weight::from_ref_time(41_852_000)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	// Storage: XCMApp PendingChanges (r:1 w:1)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:1 w:1)
+	fn cancel_change() -> Weight {
+		Weight::from_ref_time(39_317_000)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: XCMApp PendingChanges (r:1 w:1)
+	// Storage: XCMApp AssetIdToMultilocation (r:1 w:1)
+	// Storage: XCMApp MultilocationToAssetId (r:1 w:2)
+	fn apply_change() -> Weight {
+		Weight::from_ref_time(34_906_000)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	// Storage: XCMApp AssetPolicies (r:1 w:0)
+	// Storage: XCMApp PausedAssets (r:1 w:0)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Storage: XCMApp SoraAccounts (r:1 w:0)
+	// Storage: XCMApp SoraNetwork (r:1 w:0)
+	// Storage: SubstrateBridgeOutboundChannel MessageQueues (r:1 w:1)
+	// Storage: SubstrateBridgeOutboundChannel ChannelNonces (r:1 w:0)
+	// Storage: SubstrateBridgeOutboundChannel Interval (r:1 w:0)
+	fn send_to_sora() -> Weight {
+		Weight::from_ref_time(63_911_000)
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: XCMApp AssetPolicies (r:2 w:0)
+	// Storage: XCMApp PausedAssets (r:1 w:0)
+	// Storage: XCMApp TransferLimits (r:1 w:0)
+	// Storage: XCMApp AllowedDestinations (r:1 w:0)
+	// Storage: XCMApp BeneficiaryKinds (r:1 w:0)
+	// Storage: XCMApp AssetIdToMultilocation (r:1 w:0)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Storage: ParachainInfo ParachainId (r:1 w:0)
+	// Storage: PolkadotXcm SupportedVersion (r:1 w:0)
+	// Storage: PolkadotXcm VersionDiscoveryQueue (r:1 w:1)
+	// Storage: PolkadotXcm SafeXcmVersion (r:1 w:0)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	fn send_to_parachain() -> Weight {
+		Weight::from_ref_time(137_052_000)
+			.saturating_add(T::DbWeight::get().reads(15))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	// Storage: XCMApp PausedAssets (r:1 w:0)
+	// Storage: XCMApp TransferLimits (r:1 w:0)
+	// Storage: XCMApp AssetIdToMultilocation (r:1 w:0)
+	// Storage: PolkadotXcm QueryCounter (r:1 w:1)
+	// Storage: PolkadotXcm SupportedVersion (r:1 w:0)
+	// Storage: XcmpQueue OutboundXcmpStatus (r:1 w:1)
+	// Storage: XcmpQueue OutboundXcmpMessages (r:0 w:1)
+	// Storage: PolkadotXcm Queries (r:0 w:1)
+	// Storage: XCMApp PendingTransacts (r:0 w:1)
+	// Storage: XCMApp SoraAccounts (r:0 w:1)
+	fn transact() -> Weight {
+		Weight::from_ref_time(70_985_000)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	// Storage: XCMApp PendingTransacts (r:1 w:1)
+	// Storage: XCMApp SoraAccounts (r:1 w:0)
+	// Storage: SubstrateBridgeOutboundChannel MessageQueues (r:1 w:1)
+	// Storage: SubstrateBridgeOutboundChannel ChannelNonces (r:1 w:0)
+	fn report_transact() -> Weight {
+		Weight::from_ref_time(36_950_000)
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(2))
-    }
+	}
 }
 
-
 impl crate::WeightInfo for () {
 	fn register_mapping() -> Weight {
 		EXTRINSIC_FIXED_WEIGHT
@@ -96,10 +201,46 @@ impl crate::WeightInfo for () {
 	}
 
 	fn transfer() -> Weight {
-        EXTRINSIC_FIXED_WEIGHT
-    }
+		EXTRINSIC_FIXED_WEIGHT
+	}
 
 	fn register_asset() -> Weight {
-        EXTRINSIC_FIXED_WEIGHT
-    }
+		EXTRINSIC_FIXED_WEIGHT
+	}
+
+	fn set_relay_network() -> Weight {
+		EXTRINSIC_FIXED_WEIGHT
+	}
+
+	fn set_sora_network() -> Weight {
+		EXTRINSIC_FIXED_WEIGHT
+	}
+
+	fn announce_change() -> Weight {
+		EXTRINSIC_FIXED_WEIGHT
+	}
+
+	fn cancel_change() -> Weight {
+		EXTRINSIC_FIXED_WEIGHT
+	}
+
+	fn apply_change() -> Weight {
+		EXTRINSIC_FIXED_WEIGHT
+	}
+
+	fn send_to_sora() -> Weight {
+		EXTRINSIC_FIXED_WEIGHT
+	}
+
+	fn send_to_parachain() -> Weight {
+		EXTRINSIC_FIXED_WEIGHT
+	}
+
+	fn transact() -> Weight {
+		EXTRINSIC_FIXED_WEIGHT
+	}
+
+	fn report_transact() -> Weight {
+		EXTRINSIC_FIXED_WEIGHT
+	}
 }
@@ -0,0 +1,111 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Calls sent between SORA and the parachain through the bridge.
+//!
+//! [`ParachainBridgeCall`] extends `bridge_types::substrate::BridgeCall` with the calls of
+//! this parachain. The shared variants keep the indices of `BridgeCall`, so the messages
+//! encoded as `BridgeCall` are decoded without changes.
+//!
+//! The variants from index 4 are not part of `BridgeCall` yet, SORA has to add them with the
+//! same indices and fields before it sends or receives them. Their layout is pinned by
+//! `bridge_call_encoding_is_compatible` in the runtime tests.
+
+use crate::primitives::AssetId;
+use bridge_types::substrate::{DataSignerCall, MultisigVerifierCall, SubstrateAppCall, XCMAppCall};
+use bridge_types::H256;
+use codec::{Decode, Encode};
+use frame_support::{weights::Weight, RuntimeDebug};
+use scale_info::TypeInfo;
+use sp_runtime::AccountId32;
+use sp_std::{boxed::Box, vec::Vec};
+use xcm::v3::{MultiLocation, QueryId};
+
+/// Call sent through the bridge
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum ParachainBridgeCall {
+    #[codec(index = 0)]
+    SubstrateApp(SubstrateAppCall),
+    #[codec(index = 1)]
+    XCMApp(XCMAppCall),
+    #[codec(index = 2)]
+    DataSigner(DataSignerCall),
+    #[codec(index = 3)]
+    MultisigVerifier(MultisigVerifierCall),
+    /// Root level call of SORA governance, `sender` is the account which sent it on SORA
+    #[codec(index = 4)]
+    Governance { sender: AccountId32, call: BridgeGovernanceCall },
+    #[codec(index = 5)]
+    XCMAppRemote(XCMAppRemoteCall),
+    /// Result of `XCMAppRemoteCall::Transact`, sent from the parachain to SORA
+    #[codec(index = 6)]
+    XCMAppTransactReport(TransactReport),
+}
+
+/// Root level calls SORA governance is allowed to dispatch on the parachain
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum BridgeGovernanceCall {
+    /// Authorize runtime upgrade to the code with given hash
+    #[codec(index = 0)]
+    AuthorizeUpgrade { code_hash: H256 },
+    /// Set collator invulnerables
+    #[codec(index = 1)]
+    SetInvulnerables { invulnerables: Vec<AccountId32> },
+    /// Set default XCM version for destinations with unknown version
+    #[codec(index = 2)]
+    ForceDefaultXcmVersion { version: Option<xcm::Version> },
+    /// Set XCM version for the destination
+    #[codec(index = 3)]
+    ForceXcmVersion { location: Box<MultiLocation>, version: xcm::Version },
+}
+
+/// Calls of XCM app which are not part of `XCMAppCall`
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum XCMAppRemoteCall {
+    /// Transact the call on a sibling parachain on behalf of the SORA account
+    #[codec(index = 0)]
+    Transact {
+        sender: AccountId32,
+        destination: xcm::VersionedMultiLocation,
+        fee_asset_id: AssetId,
+        fee_amount: u128,
+        require_weight_at_most: Weight,
+        call: Vec<u8>,
+    },
+}
+
+/// Result of the remote `Transact` reported to SORA
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct TransactReport {
+    pub sender: AccountId32,
+    pub query_id: QueryId,
+    /// Index of the failed instruction and the error, `None` if the message succeeded
+    pub error: Option<(u32, xcm::v3::Error)>,
+}
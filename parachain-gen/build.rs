@@ -4,10 +4,15 @@ use std::{path::PathBuf, str::FromStr};
 fn main() {
     let metadata: Bytes =
         OpaqueMetadata::new(parachain_template_runtime::Runtime::metadata().into()).into();
+    // Relay chain of the runtime variant selected by `kusama`, `polkadot` or `rococo` feature
+    let variant =
+        format!("{:?}", parachain_template_runtime::variant::RELAY_NETWORK).to_lowercase();
     let out_dir = PathBuf::from_str(&std::env::var("CARGO_MANIFEST_DIR").unwrap())
         .unwrap()
         .join("src/bytes");
     std::fs::create_dir_all(&out_dir).unwrap();
+    std::fs::write(out_dir.join(format!("parachain_metadata_{}.scale", variant)), &metadata.0)
+        .unwrap();
     std::fs::write(out_dir.join("parachain_metadata.scale"), metadata.0).unwrap();
     let workspace_root = PathBuf::from_str(&std::env::var("CARGO_MANIFEST_DIR").unwrap())
         .unwrap()
@@ -0,0 +1,14 @@
+#!/bin/bash
+# Generates the metadata of every runtime variant.
+#
+# The variant is selected by a cargo feature, so the build script of parachain-gen can see
+# only one of them per build. This script builds the crate once per variant, the files are
+# written to src/bytes/parachain_metadata_<variant>.scale. Kusama is built last, so
+# src/bytes/parachain_metadata.scale is left with the default variant.
+set -ex
+
+cd "$(dirname "$0")"
+
+for variant in polkadot rococo kusama; do
+    cargo build --release -p parachain-gen --features "$variant"
+done
@@ -0,0 +1,397 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Comparison of two versions of the runtime metadata.
+//!
+//! Only the parts the relayer depends on are compared: pallet indices, calls, events, errors
+//! and storage items. Removing or changing any of them is a breaking change, additions are not.
+
+use codec::Decode;
+use frame_metadata::{
+    v14::{PalletMetadata, RuntimeMetadataV14, StorageEntryMetadata, StorageEntryType},
+    RuntimeMetadata, RuntimeMetadataPrefixed, META_RESERVED,
+};
+use scale_info::{form::PortableForm, Field, PortableRegistry, TypeDef, Variant};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    Changed,
+}
+
+/// Single difference between the metadata versions
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Change {
+    pub kind: ChangeKind,
+    /// Changed item, e.g. `call XCMApp::transfer`
+    pub item: String,
+    /// What exactly has been changed, for `ChangeKind::Changed` only
+    pub details: Option<String>,
+    pub breaking: bool,
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = match self.kind {
+            ChangeKind::Added => '+',
+            ChangeKind::Removed => '-',
+            ChangeKind::Changed => '~',
+        };
+        write!(f, "{} {}", sign, self.item)?;
+        if let Some(details) = &self.details {
+            write!(f, ": {}", details)?;
+        }
+        if self.breaking {
+            write!(f, " [breaking]")?;
+        }
+        Ok(())
+    }
+}
+
+/// Decode metadata written by the build script or returned by `state_getMetadata`
+pub fn decode_metadata(bytes: &[u8]) -> Result<RuntimeMetadataV14, String> {
+    let magic = META_RESERVED.to_le_bytes();
+    let prefixed = if bytes.starts_with(&magic) {
+        RuntimeMetadataPrefixed::decode(&mut &bytes[..])
+    } else {
+        // `state_getMetadata` wraps the metadata into `Vec<u8>`
+        Vec::<u8>::decode(&mut &bytes[..])
+            .and_then(|inner| RuntimeMetadataPrefixed::decode(&mut &inner[..]))
+    }
+    .map_err(|e| format!("Failed to decode metadata: {}", e))?;
+    match prefixed.1 {
+        RuntimeMetadata::V14(metadata) => Ok(metadata),
+        _ => Err("Only V14 metadata is supported".into()),
+    }
+}
+
+/// Compare two versions of the metadata
+pub fn diff(old: &RuntimeMetadataV14, new: &RuntimeMetadataV14) -> Vec<Change> {
+    let mut old_shapes = Shapes::new(&old.types);
+    let mut new_shapes = Shapes::new(&new.types);
+    let mut changes = Vec::new();
+
+    let old_pallets = by_name(&old.pallets, |p| &p.name);
+    let new_pallets = by_name(&new.pallets, |p| &p.name);
+    for (name, old_pallet) in &old_pallets {
+        let item = format!("pallet {}", name);
+        let new_pallet = match new_pallets.get(name) {
+            Some(pallet) => pallet,
+            None => {
+                changes.push(Change::removed(item));
+                continue;
+            },
+        };
+        if old_pallet.index != new_pallet.index {
+            changes.push(Change::changed(
+                item,
+                format!("index {} -> {}", old_pallet.index, new_pallet.index),
+                true,
+            ));
+        }
+        diff_pallet(&mut changes, name, old_pallet, new_pallet, &mut old_shapes, &mut new_shapes);
+    }
+    for (name, new_pallet) in &new_pallets {
+        if !old_pallets.contains_key(name) {
+            changes.push(Change::added(format!("pallet {} (index {})", name, new_pallet.index)));
+        }
+    }
+    changes
+}
+
+fn diff_pallet(
+    changes: &mut Vec<Change>,
+    pallet: &str,
+    old: &PalletMetadata<PortableForm>,
+    new: &PalletMetadata<PortableForm>,
+    old_shapes: &mut Shapes,
+    new_shapes: &mut Shapes,
+) {
+    let mut diff_enum = |kind: &str, old_ty: Option<u32>, new_ty: Option<u32>| {
+        let old_variants = old_ty.map(|ty| old_shapes.variants(ty)).unwrap_or_default();
+        let new_variants = new_ty.map(|ty| new_shapes.variants(ty)).unwrap_or_default();
+        for (name, (old_index, old_fields)) in &old_variants {
+            let item = format!("{} {}::{}", kind, pallet, name);
+            match new_variants.get(name) {
+                None => changes.push(Change::removed(item)),
+                Some((new_index, _)) if new_index != old_index => changes.push(Change::changed(
+                    item,
+                    format!("index {} -> {}", old_index, new_index),
+                    true,
+                )),
+                Some((_, new_fields)) if new_fields != old_fields => changes.push(Change::changed(
+                    item,
+                    format!("fields {} -> {}", old_fields, new_fields),
+                    true,
+                )),
+                Some(_) => {},
+            }
+        }
+        for name in new_variants.keys() {
+            if !old_variants.contains_key(name) {
+                changes.push(Change::added(format!("{} {}::{}", kind, pallet, name)));
+            }
+        }
+    };
+    diff_enum("call", old.calls.as_ref().map(|c| c.ty.id), new.calls.as_ref().map(|c| c.ty.id));
+    diff_enum("event", old.event.as_ref().map(|e| e.ty.id), new.event.as_ref().map(|e| e.ty.id));
+    diff_enum("error", old.error.as_ref().map(|e| e.ty.id), new.error.as_ref().map(|e| e.ty.id));
+
+    let old_storage = old
+        .storage
+        .as_ref()
+        .map(|s| by_name(&s.entries, |e| &e.name))
+        .unwrap_or_default();
+    let new_storage = new
+        .storage
+        .as_ref()
+        .map(|s| by_name(&s.entries, |e| &e.name))
+        .unwrap_or_default();
+    for (name, old_entry) in &old_storage {
+        let item = format!("storage {}::{}", pallet, name);
+        match new_storage.get(name) {
+            None => changes.push(Change::removed(item)),
+            Some(new_entry) => {
+                let old_layout = storage_layout(old_entry, old_shapes);
+                let new_layout = storage_layout(new_entry, new_shapes);
+                if old_layout != new_layout {
+                    changes.push(Change::changed(
+                        item,
+                        format!("{} -> {}", old_layout, new_layout),
+                        true,
+                    ));
+                }
+            },
+        }
+    }
+    for name in new_storage.keys() {
+        if !old_storage.contains_key(name) {
+            changes.push(Change::added(format!("storage {}::{}", pallet, name)));
+        }
+    }
+}
+
+fn storage_layout(entry: &StorageEntryMetadata<PortableForm>, shapes: &mut Shapes) -> String {
+    let ty = match &entry.ty {
+        StorageEntryType::Plain(value) => shapes.shape(value.id),
+        StorageEntryType::Map { hashers, key, value } => {
+            format!("map {:?} {} => {}", hashers, shapes.shape(key.id), shapes.shape(value.id))
+        },
+    };
+    format!("{:?} {}", entry.modifier, ty)
+}
+
+fn by_name<'a, T>(items: &'a [T], name: impl Fn(&T) -> &String) -> BTreeMap<&'a str, &'a T> {
+    items.iter().map(|item| (name(item).as_str(), item)).collect()
+}
+
+impl Change {
+    fn added(item: String) -> Self {
+        Change { kind: ChangeKind::Added, item, details: None, breaking: false }
+    }
+
+    fn removed(item: String) -> Self {
+        Change { kind: ChangeKind::Removed, item, details: None, breaking: true }
+    }
+
+    fn changed(item: String, details: String, breaking: bool) -> Self {
+        Change { kind: ChangeKind::Changed, item, details: Some(details), breaking }
+    }
+}
+
+/// Renders types of a registry to strings which don't depend on the type ids,
+/// so the same type gets the same shape in both metadata versions
+struct Shapes<'a> {
+    registry: &'a PortableRegistry,
+    cache: HashMap<u32, String>,
+    stack: Vec<u32>,
+}
+
+impl<'a> Shapes<'a> {
+    fn new(registry: &'a PortableRegistry) -> Self {
+        Shapes { registry, cache: HashMap::new(), stack: Vec::new() }
+    }
+
+    /// Variants of an enum type by name with their indices and field shapes
+    fn variants(&mut self, id: u32) -> BTreeMap<String, (u8, String)> {
+        let registry = self.registry;
+        match registry.resolve(id).map(|ty| &ty.type_def) {
+            Some(TypeDef::Variant(def)) => def
+                .variants
+                .iter()
+                .map(|v| (v.name.clone(), (v.index, self.fields(&v.fields))))
+                .collect(),
+            _ => Default::default(),
+        }
+    }
+
+    fn shape(&mut self, id: u32) -> String {
+        if let Some(shape) = self.cache.get(&id) {
+            return shape.clone();
+        }
+        let registry = self.registry;
+        let ty = match registry.resolve(id) {
+            Some(ty) => ty,
+            None => return format!("<unknown type {}>", id),
+        };
+        let path = ty.path.segments.join("::");
+        // Recursive types are cut at the first repetition
+        if self.stack.contains(&id) {
+            return path;
+        }
+        self.stack.push(id);
+        let def = match &ty.type_def {
+            TypeDef::Composite(def) => format!("{}{}", path, self.fields(&def.fields)),
+            TypeDef::Variant(def) => format!("{}{}", path, self.enum_variants(&def.variants)),
+            TypeDef::Sequence(def) => format!("[{}]", self.shape(def.type_param.id)),
+            TypeDef::Array(def) => format!("[{}; {}]", self.shape(def.type_param.id), def.len),
+            TypeDef::Tuple(def) => format!(
+                "({})",
+                def.fields.iter().map(|f| self.shape(f.id)).collect::<Vec<_>>().join(", ")
+            ),
+            TypeDef::Primitive(def) => format!("{:?}", def).to_lowercase(),
+            TypeDef::Compact(def) => format!("Compact<{}>", self.shape(def.type_param.id)),
+            TypeDef::BitSequence(def) => format!(
+                "BitVec<{}, {}>",
+                self.shape(def.bit_store_type.id),
+                self.shape(def.bit_order_type.id)
+            ),
+        };
+        self.stack.pop();
+        self.cache.insert(id, def.clone());
+        def
+    }
+
+    fn fields(&mut self, fields: &[Field<PortableForm>]) -> String {
+        let fields = fields
+            .iter()
+            .map(|f| match &f.name {
+                Some(name) => format!("{}: {}", name, self.shape(f.ty.id)),
+                None => self.shape(f.ty.id),
+            })
+            .collect::<Vec<_>>();
+        format!("{{{}}}", fields.join(", "))
+    }
+
+    fn enum_variants(&mut self, variants: &[Variant<PortableForm>]) -> String {
+        let variants = variants
+            .iter()
+            .map(|v| format!("{}={}{}", v.name, v.index, self.fields(&v.fields)))
+            .collect::<Vec<_>>();
+        format!("<{}>", variants.join(" | "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use frame_metadata::v14::{ExtrinsicMetadata, PalletCallMetadata};
+    use scale_info::{meta_type, TypeInfo};
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(TypeInfo)]
+    enum CallV1 {
+        #[codec(index = 0)]
+        transfer { amount: u128 },
+        #[codec(index = 1)]
+        register_asset { asset_id: [u8; 32] },
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(TypeInfo)]
+    enum CallV2 {
+        #[codec(index = 0)]
+        transfer { amount: u64 },
+        #[codec(index = 2)]
+        set_relay_network { network: u8 },
+    }
+
+    fn metadata<Call: TypeInfo + 'static>(index: u8) -> RuntimeMetadataV14 {
+        let pallet = PalletMetadata {
+            name: "XCMApp",
+            storage: None,
+            calls: Some(PalletCallMetadata { ty: meta_type::<Call>() }),
+            event: None,
+            constants: vec![],
+            error: None,
+            index,
+        };
+        let extrinsic =
+            ExtrinsicMetadata { ty: meta_type::<()>(), version: 4, signed_extensions: vec![] };
+        RuntimeMetadataV14::new(vec![pallet], extrinsic, meta_type::<()>())
+    }
+
+    #[test]
+    fn same_metadata_has_no_changes() {
+        assert_eq!(diff(&metadata::<CallV1>(101), &metadata::<CallV1>(101)), vec![]);
+    }
+
+    #[test]
+    fn changed_calls_are_reported() {
+        let changes = diff(&metadata::<CallV1>(101), &metadata::<CallV2>(101));
+        assert_eq!(
+            changes,
+            vec![
+                Change::removed("call XCMApp::register_asset".into()),
+                Change::changed(
+                    "call XCMApp::transfer".into(),
+                    "fields {amount: u128} -> {amount: u64}".into(),
+                    true
+                ),
+                Change::added("call XCMApp::set_relay_network".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn changed_pallet_index_is_breaking() {
+        let changes = diff(&metadata::<CallV1>(101), &metadata::<CallV1>(102));
+        assert_eq!(
+            changes,
+            vec![Change::changed("pallet XCMApp".into(), "index 101 -> 102".into(), true)]
+        );
+        assert!(changes.iter().all(|c| c.breaking));
+    }
+
+    #[test]
+    fn added_pallet_is_not_breaking() {
+        let mut new = metadata::<CallV1>(101);
+        let mut pallet = new.pallets[0].clone();
+        pallet.name = "XCMAppSudoWrapper".into();
+        pallet.index = 103;
+        new.pallets.push(pallet);
+        let changes = diff(&metadata::<CallV1>(101), &new);
+        assert_eq!(changes, vec![Change::added("pallet XCMAppSudoWrapper (index 103)".into())]);
+        assert!(!changes[0].breaking);
+    }
+}
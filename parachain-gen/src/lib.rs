@@ -28,4 +28,46 @@
 // STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-// This lib is empty and works as a trigger to generate metadata during the build.
+//! Metadata of the parachain runtime and typed client bindings generated from it.
+//!
+//! Build script writes the metadata of the runtime variant selected by `kusama`, `polkadot`
+//! or `rococo` feature (Kusama by default) to `src/bytes/parachain_metadata_<variant>.scale`
+//! and to `src/bytes/parachain_metadata.scale`. Bindings for calls, events and storage of
+//! every pallet are generated with `subxt` and available as [`parachain_runtime`].
+//!
+//! A build sees only one variant, run `generate_metadata.sh` to generate the metadata of all
+//! of them.
+
+pub mod diff;
+
+pub use subxt;
+
+/// SCALE encoded metadata of the runtime this crate is built for
+pub const PARACHAIN_METADATA: &[u8] = include_bytes!("bytes/parachain_metadata.scale");
+
+#[cfg_attr(
+    not(any(feature = "polkadot", feature = "rococo")),
+    subxt::subxt(
+        runtime_metadata_path = "src/bytes/parachain_metadata_kusama.scale",
+        derive_for_all_types = "Clone"
+    )
+)]
+#[cfg_attr(
+    feature = "polkadot",
+    subxt::subxt(
+        runtime_metadata_path = "src/bytes/parachain_metadata_polkadot.scale",
+        derive_for_all_types = "Clone"
+    )
+)]
+#[cfg_attr(
+    feature = "rococo",
+    subxt::subxt(
+        runtime_metadata_path = "src/bytes/parachain_metadata_rococo.scale",
+        derive_for_all_types = "Clone"
+    )
+)]
+pub mod parachain_runtime {}
+
+/// Client config of the parachain. Runtime uses `MultiAddress<AccountId, ()>`, but the encoding
+/// of `MultiAddress::Id` doesn't depend on the index type, so Substrate defaults fit
+pub type ParachainConfig = subxt::SubstrateConfig;
@@ -0,0 +1,256 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use crate::{
+    xcm_config::LocationToAccountId, AccountId, Balance, Balances, BlockNumber, CollatorSelection,
+    Perbill, PolkadotXcm, Runtime, RuntimeCall, Treasury, XCMApp,
+};
+use frame_support::traits::{Contains, Currency, Imbalance, OnUnbalanced};
+use hrmp_manager_runtime_api::SovereignAccountInfo;
+use orml_traits::{location::Parse, MultiCurrency};
+use parachain_common::primitives::AssetId;
+use sp_runtime::traits::{Convert, Zero};
+use sp_std::prelude::*;
+use xcm::latest::prelude::*;
+use xcm_builder::TakeRevenue;
+
+type NegativeImbalance = <Balances as Currency<AccountId>>::NegativeImbalance;
+
+/// Share of the fees going to the treasury, the rest goes to the collator pot
+pub const TREASURY_FEE_SHARE: Perbill = Perbill::from_percent(20);
+
+/// Moves the imbalance to the collator pot
+pub struct ToCollatorPot;
+impl OnUnbalanced<NegativeImbalance> for ToCollatorPot {
+    fn on_nonzero_unbalanced(amount: NegativeImbalance) {
+        Balances::resolve_creating(&CollatorSelection::account_id(), amount);
+    }
+}
+
+/// Splits the transaction fees and the dust between the treasury and the collator pot
+pub struct DealWithFees;
+impl OnUnbalanced<NegativeImbalance> for DealWithFees {
+    fn on_nonzero_unbalanced(amount: NegativeImbalance) {
+        let to_treasury = TREASURY_FEE_SHARE * amount.peek();
+        let (treasury, pot) = amount.split(to_treasury);
+        Treasury::on_unbalanced(treasury);
+        ToCollatorPot::on_unbalanced(pot);
+    }
+}
+
+/// Accounts receiving the fees, their balances below the existential deposit are kept
+pub struct FeeAccounts;
+impl Contains<AccountId> for FeeAccounts {
+    fn contains(account: &AccountId) -> bool {
+        *account == Treasury::account_id() || *account == CollatorSelection::account_id()
+    }
+}
+
+/// Splits the fees paid in the asset between the treasury and the collator pot in the same
+/// proportion as `DealWithFees`. Only the assets held on this parachain are credited, crediting
+/// the others would send them to SORA, so their fees are burned
+pub fn deal_with_asset_fees(asset_id: AssetId, amount: Balance) {
+    if !XCMApp::is_held_locally(asset_id) {
+        log::debug!(target: "xcm::weight", "fees in asset {:?} are burned", asset_id);
+        return;
+    }
+    let to_treasury = TREASURY_FEE_SHARE * amount;
+    for (account, share) in [
+        (Treasury::account_id(), to_treasury),
+        (CollatorSelection::account_id(), amount.saturating_sub(to_treasury)),
+    ] {
+        if share.is_zero() {
+            continue;
+        }
+        if let Err(e) = <XCMApp as MultiCurrency<AccountId>>::deposit(asset_id, &account, share) {
+            log::warn!(target: "xcm::weight", "failed to take fees revenue: {:?}", e);
+        }
+    }
+}
+
+/// Splits the XCM execution fees between the treasury and the collator pot, the native token
+/// is handled by `DealWithFees` and the other assets by `deal_with_asset_fees`
+pub struct XcmFeesRevenue;
+impl TakeRevenue for XcmFeesRevenue {
+    fn take_revenue(revenue: MultiAsset) {
+        let amount = match revenue.fun {
+            Fungible(amount) if !amount.is_zero() => amount,
+            _ => return,
+        };
+        if revenue.id == Concrete(MultiLocation::here()) {
+            DealWithFees::on_unbalanced(Balances::issue(amount));
+            return;
+        }
+        if let Some(asset_id) = <XCMApp as Convert<MultiAsset, Option<AssetId>>>::convert(revenue) {
+            deal_with_asset_fees(asset_id, amount);
+        }
+    }
+}
+
+/// Assets which have a multilocation mapping in `XCMApp`, are held on this parachain and are
+/// not paused
+pub struct MappedXcmAppAssets;
+impl Contains<AssetId> for MappedXcmAppAssets {
+    fn contains(asset_id: &AssetId) -> bool {
+        XCMApp::get_multilocation_from_asset_id(asset_id).is_some()
+            && XCMApp::is_held_locally(*asset_id)
+            && !XCMApp::is_asset_paused(asset_id)
+    }
+}
+
+/// Transaction fees paid in assets, split by `deal_with_asset_fees`
+pub struct DealWithAssetFees;
+impl asset_fee_payment::OnAssetFee<Balance> for DealWithAssetFees {
+    fn on_asset_fee(asset_id: AssetId, amount: Balance) {
+        deal_with_asset_fees(asset_id, amount);
+    }
+}
+
+/// Queries of the remote `Transact` results answered through `PolkadotXcm`
+pub struct XcmAppTransactQueries;
+impl xcm_app::TransactQueryHandler<RuntimeCall, BlockNumber> for XcmAppTransactQueries {
+    fn new_notify_query(
+        responder: MultiLocation,
+        notify: RuntimeCall,
+        timeout: BlockNumber,
+        querier: MultiLocation,
+    ) -> QueryId {
+        PolkadotXcm::new_notify_query(responder, notify, timeout, querier)
+    }
+}
+
+/// Sovereign accounts of the reserve chains of the assets mapped in `XCMApp` with their
+/// balances on this parachain
+pub fn reserve_sovereign_accounts() -> Vec<SovereignAccountInfo<AccountId, AssetId, Balance>> {
+    xcm_app::AssetIdToMultilocation::<Runtime>::iter()
+        .filter_map(|(asset_id, location)| {
+            let reserve = location.chain_part()?;
+            let account = <LocationToAccountId as xcm_executor::traits::Convert<
+                MultiLocation,
+                AccountId,
+            >>::convert_ref(&reserve)
+            .ok()?;
+            Some(SovereignAccountInfo {
+                asset_id,
+                reserve: reserve.into(),
+                native_balance: Balances::free_balance(&account),
+                asset_balance: <XCMApp as MultiCurrency<AccountId>>::free_balance(
+                    asset_id, &account,
+                ),
+                account,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::UNIT;
+
+    fn new_test_ext() -> sp_io::TestExternalities {
+        let t = frame_system::GenesisConfig::default().build_storage::<Runtime>().unwrap();
+        sp_io::TestExternalities::new(t)
+    }
+
+    #[test]
+    fn fees_are_split_between_treasury_and_pot() {
+        new_test_ext().execute_with(|| {
+            DealWithFees::on_unbalanceds(
+                vec![Balances::issue(8 * UNIT), Balances::issue(2 * UNIT)].into_iter(),
+            );
+            assert_eq!(Balances::free_balance(Treasury::account_id()), 2 * UNIT);
+            assert_eq!(Balances::free_balance(CollatorSelection::account_id()), 8 * UNIT);
+            assert_eq!(Balances::total_issuance(), 10 * UNIT);
+        });
+    }
+
+    #[test]
+    fn dust_goes_to_treasury_and_pot() {
+        new_test_ext().execute_with(|| {
+            <Runtime as pallet_balances::Config>::DustRemoval::on_unbalanced(Balances::issue(
+                5 * UNIT,
+            ));
+            assert_eq!(Balances::free_balance(Treasury::account_id()), UNIT);
+            assert_eq!(Balances::free_balance(CollatorSelection::account_id()), 4 * UNIT);
+        });
+    }
+
+    fn revenue(asset_id: AssetId, amount: Balance) -> MultiAsset {
+        let location = XCMApp::get_multilocation_from_asset_id(asset_id).unwrap();
+        (location, amount).into()
+    }
+
+    fn outbound_messages() -> usize {
+        frame_system::Pallet::<Runtime>::events()
+            .iter()
+            .filter(|r| matches!(r.event, crate::RuntimeEvent::SubstrateBridgeOutboundChannel(_)))
+            .count()
+    }
+
+    #[test]
+    fn asset_fees_are_burned_unless_held_locally() {
+        new_test_ext().execute_with(|| {
+            frame_system::Pallet::<Runtime>::set_block_number(1);
+            let asset_id = AssetId::from([1; 32]);
+            assert!(XCMApp::register_mapping(asset_id, MultiLocation::parent()).is_ok());
+
+            XcmFeesRevenue::take_revenue(revenue(asset_id, 10 * UNIT));
+            assert_eq!(outbound_messages(), 0);
+            assert!(<XCMApp as MultiCurrency<AccountId>>::free_balance(
+                asset_id,
+                &Treasury::account_id()
+            )
+            .is_zero());
+
+            assert!(XCMApp::do_apply_change(xcm_app::DelayedChange::SetAssetPolicy {
+                asset_id,
+                policy: xcm_app::AssetPolicy::HoldLocally,
+            })
+            .is_ok());
+            XcmFeesRevenue::take_revenue(revenue(asset_id, 10 * UNIT));
+            assert_eq!(outbound_messages(), 0);
+            assert_eq!(
+                <XCMApp as MultiCurrency<AccountId>>::free_balance(
+                    asset_id,
+                    &Treasury::account_id()
+                ),
+                2 * UNIT
+            );
+            assert_eq!(
+                <XCMApp as MultiCurrency<AccountId>>::free_balance(
+                    asset_id,
+                    &CollatorSelection::account_id()
+                ),
+                8 * UNIT
+            );
+        });
+    }
+}
@@ -38,8 +38,12 @@ include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 #[cfg(test)]
 mod xcm_tests;
 
+mod impls;
+pub mod maintenance;
 mod migrations;
+pub mod proxy;
 mod trader;
+pub mod variant;
 mod weights;
 pub mod xcm_config;
 
@@ -68,7 +72,7 @@ use sp_version::RuntimeVersion;
 
 use frame_support::{
     construct_runtime, parameter_types,
-    traits::Everything,
+    traits::{EitherOfDiverse, EqualPrivilegeOnly, Everything},
     weights::{
         constants::{BlockExecutionWeight, ExtrinsicBaseWeight, WEIGHT_REF_TIME_PER_SECOND},
         ConstantMultiplier, Weight, WeightToFeeCoefficient, WeightToFeeCoefficients,
@@ -80,6 +84,11 @@ use frame_system::{
     limits::{BlockLength, BlockWeights},
     EnsureRoot,
 };
+use orml_traits::parameter_type_with_key;
+use parachain_common::{
+    bridge::{BridgeGovernanceCall, ParachainBridgeCall},
+    primitives::AssetId,
+};
 pub use sp_beefy::crypto::AuthorityId as BeefyId;
 use sp_beefy::mmr::MmrLeafVersion;
 pub use sp_consensus_aura::sr25519::AuthorityId as AuraId;
@@ -141,7 +150,7 @@ pub type SignedExtra = (
     frame_system::CheckEra<Runtime>,
     frame_system::CheckNonce<Runtime>,
     frame_system::CheckWeight<Runtime>,
-    pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+    asset_fee_payment::ChargeAssetTxPayment<Runtime>,
 );
 
 /// Unchecked extrinsic type as expected by this runtime.
@@ -157,7 +166,7 @@ pub type Executive = frame_executive::Executive<
     Block,
     frame_system::ChainContext<Runtime>,
     Runtime,
-    AllPalletsWithSystem,
+    maintenance_mode::ExecutiveHooks<Runtime>,
     migrations::Migrations,
 >;
 
@@ -212,15 +221,43 @@ impl_opaque_keys! {
     }
 }
 
+#[cfg(not(any(feature = "polkadot", feature = "rococo")))]
+#[sp_version::runtime_version]
+pub const VERSION: RuntimeVersion = RuntimeVersion {
+    spec_name: create_runtime_str!("sora_ksm"),
+    impl_name: create_runtime_str!("sora_ksm"),
+    authoring_version: 1,
+    spec_version: 6,
+    impl_version: 0,
+    apis: RUNTIME_API_VERSIONS,
+    transaction_version: 4,
+    state_version: 1,
+};
+
+#[cfg(feature = "polkadot")]
+#[sp_version::runtime_version]
+pub const VERSION: RuntimeVersion = RuntimeVersion {
+    spec_name: create_runtime_str!("sora_dot"),
+    impl_name: create_runtime_str!("sora_dot"),
+    authoring_version: 1,
+    spec_version: 6,
+    impl_version: 0,
+    apis: RUNTIME_API_VERSIONS,
+    transaction_version: 4,
+    state_version: 1,
+};
+
+// Rococo testnet was started with the Kusama runtime, changing the name would reject `set_code`
+#[cfg(feature = "rococo")]
 #[sp_version::runtime_version]
 pub const VERSION: RuntimeVersion = RuntimeVersion {
     spec_name: create_runtime_str!("sora_ksm"),
     impl_name: create_runtime_str!("sora_ksm"),
     authoring_version: 1,
-    spec_version: 3,
+    spec_version: 6,
     impl_version: 0,
     apis: RUNTIME_API_VERSIONS,
-    transaction_version: 3,
+    transaction_version: 4,
     state_version: 1,
 };
 
@@ -249,6 +286,11 @@ pub const MICROUNIT: Balance = 1_000_000;
 /// The existential deposit. Set to 1/10 of the Connected Relay Chain.
 pub const EXISTENTIAL_DEPOSIT: Balance = MILLIUNIT;
 
+/// Deposit for keeping `items` storage items of `bytes` total size
+pub const fn deposit(items: u32, bytes: u32) -> Balance {
+    items as Balance * 20 * MILLIUNIT + (bytes as Balance) * 100 * MICROUNIT
+}
+
 /// We assume that ~5% of the block weight is consumed by `on_initialize` handlers. This is
 /// used to limit the maximal weight of a single extrinsic.
 const AVERAGE_ON_INITIALIZE_RATIO: Perbill = Perbill::from_percent(5);
@@ -298,7 +340,7 @@ parameter_types! {
         })
         .avg_block_initialization(AVERAGE_ON_INITIALIZE_RATIO)
         .build_or_panic();
-    pub const SS58Prefix: u16 = 420;
+    pub const SS58Prefix: u16 = variant::SS58_PREFIX;
 }
 
 // Configure FRAME pallets to include in runtime.
@@ -339,9 +381,9 @@ impl frame_system::Config for Runtime {
     /// The weight of database operations that the runtime can invoke.
     type DbWeight = RocksDbWeight;
     /// The basic call filter to use in dispatchable.
-    type BaseCallFilter = Everything;
+    type BaseCallFilter = MaintenanceMode;
     /// Weight information for the extrinsics of this pallet.
-    type SystemWeightInfo = ();
+    type SystemWeightInfo = weights::frame_system::WeightInfo<Runtime>;
     /// Block & extrinsics weights: base values and limits.
     type BlockWeights = RuntimeBlockWeights;
     /// The maximum length of a block (in bytes).
@@ -362,7 +404,7 @@ impl pallet_timestamp::Config for Runtime {
     type Moment = u64;
     type OnTimestampSet = ();
     type MinimumPeriod = MinimumPeriod;
-    type WeightInfo = ();
+    type WeightInfo = weights::pallet_timestamp::WeightInfo<Runtime>;
 }
 
 parameter_types! {
@@ -386,10 +428,10 @@ impl pallet_balances::Config for Runtime {
     type Balance = Balance;
     /// The ubiquitous event type.
     type RuntimeEvent = RuntimeEvent;
-    type DustRemoval = ();
+    type DustRemoval = impls::DealWithFees;
     type ExistentialDeposit = ExistentialDeposit;
     type AccountStore = System;
-    type WeightInfo = pallet_balances::weights::SubstrateWeight<Runtime>;
+    type WeightInfo = weights::pallet_balances::WeightInfo<Runtime>;
     type MaxReserves = MaxReserves;
     type ReserveIdentifier = [u8; 8];
 }
@@ -400,15 +442,82 @@ parameter_types! {
     pub const OperationalFeeMultiplier: u8 = 5;
 }
 
+parameter_types! {
+    pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
+    pub const ProposalBond: Permill = Permill::from_percent(5);
+    pub const ProposalBondMinimum: Balance = 100 * UNIT;
+    pub const ProposalBondMaximum: Option<Balance> = Some(500 * UNIT);
+    pub const SpendPeriod: BlockNumber = 6 * DAYS;
+    pub const Burn: Permill = Permill::zero();
+    pub const MaxApprovals: u32 = 100;
+}
+
+impl pallet_treasury::Config for Runtime {
+    type PalletId = TreasuryPalletId;
+    type Currency = Balances;
+    type ApproveOrigin = RootOrCouncil;
+    type RejectOrigin = RootOrCouncil;
+    type RuntimeEvent = RuntimeEvent;
+    type OnSlash = Treasury;
+    type ProposalBond = ProposalBond;
+    type ProposalBondMinimum = ProposalBondMinimum;
+    type ProposalBondMaximum = ProposalBondMaximum;
+    type SpendPeriod = SpendPeriod;
+    type Burn = Burn;
+    type BurnDestination = ();
+    type SpendFunds = ();
+    type WeightInfo = weights::pallet_treasury::WeightInfo<Runtime>;
+    type MaxApprovals = MaxApprovals;
+    type SpendOrigin = frame_support::traits::NeverEnsureOrigin<Balance>;
+}
+
 impl pallet_transaction_payment::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
-    type OnChargeTransaction = pallet_transaction_payment::CurrencyAdapter<Balances, ()>;
+    type OnChargeTransaction =
+        pallet_transaction_payment::CurrencyAdapter<Balances, impls::DealWithFees>;
     type WeightToFee = WeightToFee;
     type LengthToFee = ConstantMultiplier<Balance, TransactionByteFee>;
     type FeeMultiplierUpdate = SlowAdjustingFeeUpdate<Self>;
     type OperationalFeeMultiplier = OperationalFeeMultiplier;
 }
 
+/// Existential deposit of the assets governance has not set one for in `XCMApp`
+pub const DEFAULT_ASSET_EXISTENTIAL_DEPOSIT: Balance = 1;
+
+parameter_type_with_key! {
+    pub ExistentialDeposits: |asset_id: AssetId| -> Balance {
+        XCMApp::existential_deposit(asset_id).unwrap_or(DEFAULT_ASSET_EXISTENTIAL_DEPOSIT)
+    };
+}
+
+parameter_types! {
+    pub const MaxTokenLocks: u32 = 50;
+    pub const MaxTokenReserves: u32 = 50;
+}
+
+impl orml_tokens::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = Balance;
+    type Amount = i128;
+    type CurrencyId = AssetId;
+    type WeightInfo = weights::orml_tokens::WeightInfo<Runtime>;
+    type ExistentialDeposits = ExistentialDeposits;
+    type CurrencyHooks = ();
+    type MaxLocks = MaxTokenLocks;
+    type MaxReserves = MaxTokenReserves;
+    type ReserveIdentifier = [u8; 8];
+    type DustRemovalWhitelist = impls::FeeAccounts;
+}
+
+impl asset_fee_payment::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = asset_fee_payment::weights::WeightInfo<Runtime>;
+    type Assets = Tokens;
+    type FeeAssets = impls::MappedXcmAppAssets;
+    type OnAssetFee = impls::DealWithAssetFees;
+    type ManagerOrigin = RootOrCouncil;
+}
+
 parameter_types! {
     pub const ReservedXcmpWeight: Weight = MAXIMUM_BLOCK_WEIGHT.saturating_div(4);
     pub const ReservedDmpWeight: Weight = MAXIMUM_BLOCK_WEIGHT.saturating_div(4);
@@ -418,7 +527,7 @@ impl cumulus_pallet_parachain_system::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type OnSystemEvent = ();
     type SelfParaId = parachain_info::Pallet<Runtime>;
-    type DmpMessageHandler = DmpQueue;
+    type DmpMessageHandler = MaintenanceMode;
     type ReservedDmpWeight = ReservedDmpWeight;
     type OutboundXcmpMessageSource = XcmpQueue;
     type XcmpMessageHandler = XcmpQueue;
@@ -428,6 +537,19 @@ impl cumulus_pallet_parachain_system::Config for Runtime {
 
 impl parachain_info::Config for Runtime {}
 
+impl maintenance_mode::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = maintenance_mode::weights::WeightInfo<Runtime>;
+    type NormalCallFilter = Everything;
+    type MaintenanceCallFilter = maintenance::MaintenanceFilter;
+    type MaintenanceOrigin = RootOrCouncilOrTechnicalCommittee;
+    type XcmExecutionManager = maintenance::XcmExecutionManager;
+    type NormalDmpHandler = DmpQueue;
+    type MaintenanceDmpHandler = maintenance::MaintenanceDmpHandler;
+    type NormalExecutiveHooks = AllPalletsWithSystem;
+    type MaintenanceExecutiveHooks = maintenance::MaintenanceHooks;
+}
+
 /// Configure Merkle Mountain Range pallet.
 impl pallet_mmr::Config for Runtime {
     const INDEXING_PREFIX: &'static [u8] = b"mmr";
@@ -473,6 +595,179 @@ impl pallet_sudo::Config for Runtime {
     type RuntimeCall = RuntimeCall;
 }
 
+parameter_types! {
+    pub const CouncilMotionDuration: BlockNumber = 3 * DAYS;
+    pub const CouncilMaxProposals: u32 = 100;
+    pub const CouncilMaxMembers: u32 = 100;
+    pub const TechnicalMotionDuration: BlockNumber = 3 * DAYS;
+    pub const TechnicalMaxProposals: u32 = 100;
+    pub const TechnicalMaxMembers: u32 = 100;
+}
+
+pub type CouncilCollective = pallet_collective::Instance1;
+pub type TechnicalCollective = pallet_collective::Instance2;
+
+/// Root or more than a half of the Council
+pub type RootOrCouncil = EitherOfDiverse<
+    EnsureRoot<AccountId>,
+    pallet_collective::EnsureProportionMoreThan<AccountId, CouncilCollective, 1, 2>,
+>;
+
+/// Root, more than a half of the Council or more than a half of the Technical Committee
+pub type RootOrCouncilOrTechnicalCommittee = EitherOfDiverse<
+    RootOrCouncil,
+    pallet_collective::EnsureProportionMoreThan<AccountId, TechnicalCollective, 1, 2>,
+>;
+
+/// Root or at least two thirds of the Technical Committee
+pub type RootOrTwoThirdsTechnicalCommittee = EitherOfDiverse<
+    EnsureRoot<AccountId>,
+    pallet_collective::EnsureProportionAtLeast<AccountId, TechnicalCollective, 2, 3>,
+>;
+
+impl pallet_collective::Config<CouncilCollective> for Runtime {
+    type RuntimeOrigin = RuntimeOrigin;
+    type Proposal = RuntimeCall;
+    type RuntimeEvent = RuntimeEvent;
+    type MotionDuration = CouncilMotionDuration;
+    type MaxProposals = CouncilMaxProposals;
+    type MaxMembers = CouncilMaxMembers;
+    type DefaultVote = pallet_collective::PrimeDefaultVote;
+    type SetMembersOrigin = EnsureRoot<AccountId>;
+    type WeightInfo = weights::pallet_collective::WeightInfo<Runtime>;
+}
+
+impl pallet_membership::Config<pallet_membership::Instance1> for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type AddOrigin = RootOrCouncil;
+    type RemoveOrigin = RootOrCouncil;
+    type SwapOrigin = RootOrCouncil;
+    type ResetOrigin = RootOrCouncil;
+    type PrimeOrigin = RootOrCouncil;
+    type MembershipInitialized = Council;
+    type MembershipChanged = Council;
+    type MaxMembers = CouncilMaxMembers;
+    type WeightInfo = weights::pallet_membership::WeightInfo<Runtime>;
+}
+
+impl pallet_collective::Config<TechnicalCollective> for Runtime {
+    type RuntimeOrigin = RuntimeOrigin;
+    type Proposal = RuntimeCall;
+    type RuntimeEvent = RuntimeEvent;
+    type MotionDuration = TechnicalMotionDuration;
+    type MaxProposals = TechnicalMaxProposals;
+    type MaxMembers = TechnicalMaxMembers;
+    type DefaultVote = pallet_collective::PrimeDefaultVote;
+    type SetMembersOrigin = EnsureRoot<AccountId>;
+    type WeightInfo = weights::pallet_collective::WeightInfo<Runtime>;
+}
+
+impl pallet_membership::Config<pallet_membership::Instance2> for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type AddOrigin = RootOrCouncil;
+    type RemoveOrigin = RootOrCouncil;
+    type SwapOrigin = RootOrCouncil;
+    type ResetOrigin = RootOrCouncil;
+    type PrimeOrigin = RootOrCouncil;
+    type MembershipInitialized = TechnicalCommittee;
+    type MembershipChanged = TechnicalCommittee;
+    type MaxMembers = TechnicalMaxMembers;
+    type WeightInfo = weights::pallet_membership::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+    pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) *
+        RuntimeBlockWeights::get().max_block;
+    pub const MaxScheduledPerBlock: u32 = 50;
+    pub const PreimageBaseDeposit: Balance = UNIT;
+    pub const PreimageByteDeposit: Balance = MICROUNIT;
+}
+
+impl pallet_scheduler::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeOrigin = RuntimeOrigin;
+    type PalletsOrigin = OriginCaller;
+    type RuntimeCall = RuntimeCall;
+    type MaximumWeight = MaximumSchedulerWeight;
+    type ScheduleOrigin = RootOrCouncil;
+    type MaxScheduledPerBlock = MaxScheduledPerBlock;
+    type WeightInfo = weights::pallet_scheduler::WeightInfo<Runtime>;
+    type OriginPrivilegeCmp = EqualPrivilegeOnly;
+    type Preimages = Preimage;
+}
+
+impl pallet_preimage::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = weights::pallet_preimage::WeightInfo<Runtime>;
+    type Currency = Balances;
+    type ManagerOrigin = RootOrCouncil;
+    type BaseDeposit = PreimageBaseDeposit;
+    type ByteDeposit = PreimageByteDeposit;
+}
+
+/// Root calls are dispatched by governance in two steps: the Council whitelists the hash of
+/// the call and the Technical Committee dispatches it
+impl pallet_whitelist::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeCall = RuntimeCall;
+    type WhitelistOrigin = RootOrCouncil;
+    type DispatchWhitelistedOrigin = RootOrTwoThirdsTechnicalCommittee;
+    type Preimages = Preimage;
+    type WeightInfo = pallet_whitelist::weights::SubstrateWeight<Runtime>;
+}
+
+impl pallet_utility::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeCall = RuntimeCall;
+    type PalletsOrigin = OriginCaller;
+    type WeightInfo = weights::pallet_utility::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+    // One storage item; key size is 32; value is size 4+4+16+32 bytes = 56 bytes.
+    pub const DepositBase: Balance = deposit(1, 88);
+    // Additional storage item size of 32 bytes.
+    pub const DepositFactor: Balance = deposit(0, 32);
+    pub const MaxSignatories: u32 = 100;
+}
+
+impl pallet_multisig::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeCall = RuntimeCall;
+    type Currency = Balances;
+    type DepositBase = DepositBase;
+    type DepositFactor = DepositFactor;
+    type MaxSignatories = MaxSignatories;
+    type WeightInfo = weights::pallet_multisig::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+    // One storage item; key size 32, value size 8.
+    pub const ProxyDepositBase: Balance = deposit(1, 40);
+    // Additional storage item size of 33 bytes.
+    pub const ProxyDepositFactor: Balance = deposit(0, 33);
+    pub const MaxProxies: u16 = 32;
+    // One storage item; key size 32, value size 16
+    pub const AnnouncementDepositBase: Balance = deposit(1, 48);
+    pub const AnnouncementDepositFactor: Balance = deposit(0, 66);
+    pub const MaxPending: u16 = 32;
+}
+
+impl pallet_proxy::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeCall = RuntimeCall;
+    type Currency = Balances;
+    type ProxyType = proxy::ProxyType;
+    type ProxyDepositBase = ProxyDepositBase;
+    type ProxyDepositFactor = ProxyDepositFactor;
+    type MaxProxies = MaxProxies;
+    type WeightInfo = weights::pallet_proxy::WeightInfo<Runtime>;
+    type MaxPending = MaxPending;
+    type CallHasher = BlakeTwo256;
+    type AnnouncementDepositBase = AnnouncementDepositBase;
+    type AnnouncementDepositFactor = AnnouncementDepositFactor;
+}
+
 impl cumulus_pallet_aura_ext::Config for Runtime {}
 
 #[cfg(not(test))]
@@ -481,10 +776,10 @@ impl cumulus_pallet_xcmp_queue::Config for Runtime {
     type XcmExecutor = XcmExecutor<XcmConfig>;
     type ChannelInfo = ParachainSystem;
     type VersionWrapper = ();
-    type ExecuteOverweightOrigin = EnsureRoot<AccountId>;
+    type ExecuteOverweightOrigin = RootOrCouncilOrTechnicalCommittee;
     type ControllerOrigin = EnsureRoot<AccountId>;
     type ControllerOriginConverter = XcmOriginToTransactDispatchOrigin;
-    type WeightInfo = ();
+    type WeightInfo = weights::cumulus_pallet_xcmp_queue::WeightInfo<Runtime>;
     // TODO! look at this parameter
     type PriceForSiblingDelivery = ();
 }
@@ -495,17 +790,36 @@ impl cumulus_pallet_xcmp_queue::Config for Runtime {
     type XcmExecutor = XcmExecutor<XcmConfig>;
     type ChannelInfo = xcm_tests::ChannelInfo;
     type VersionWrapper = ();
-    type ExecuteOverweightOrigin = EnsureRoot<AccountId>;
+    type ExecuteOverweightOrigin = RootOrCouncilOrTechnicalCommittee;
     type ControllerOrigin = EnsureRoot<AccountId>;
     type ControllerOriginConverter = XcmOriginToTransactDispatchOrigin;
-    type WeightInfo = ();
+    type WeightInfo = weights::cumulus_pallet_xcmp_queue::WeightInfo<Runtime>;
     type PriceForSiblingDelivery = ();
 }
 
 impl cumulus_pallet_dmp_queue::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type XcmExecutor = XcmExecutor<XcmConfig>;
-    type ExecuteOverweightOrigin = EnsureRoot<AccountId>;
+    type ExecuteOverweightOrigin = RootOrCouncilOrTechnicalCommittee;
+}
+
+parameter_types! {
+    /// Index of the `hrmp` pallet in the Rococo, Kusama and Polkadot runtimes
+    pub const RelayHrmpPalletIndex: u8 = 60;
+    pub const HrmpRelayFee: u128 = 100_000_000_000;
+    pub const HrmpTransactWeight: Weight = Weight::from_parts(4_000_000_000, 65_536);
+}
+
+impl hrmp_manager::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = hrmp_manager::weights::WeightInfo<Runtime>;
+    type ManagerOrigin = RootOrCouncil;
+    type SelfParaId = ParachainInfo;
+    type XcmSender = xcm_config::XcmRouter;
+    type ChannelInfo = ParachainSystem;
+    type HrmpPalletIndex = RelayHrmpPalletIndex;
+    type RelayFee = HrmpRelayFee;
+    type TransactWeight = HrmpTransactWeight;
 }
 
 parameter_types! {
@@ -525,7 +839,7 @@ impl pallet_session::Config for Runtime {
     // Essentially just Aura, but lets be pedantic.
     type SessionHandler = <SessionKeys as sp_runtime::traits::OpaqueKeys>::KeyTypeIdProviders;
     type Keys = SessionKeys;
-    type WeightInfo = ();
+    type WeightInfo = weights::pallet_session::WeightInfo<Runtime>;
 }
 
 impl pallet_aura::Config for Runtime {
@@ -543,8 +857,8 @@ parameter_types! {
     pub const ExecutiveBody: BodyId = BodyId::Executive;
 }
 
-// We allow root only to execute privileged collator selection operations.
-pub type CollatorSelectionUpdateOrigin = EnsureRoot<AccountId>;
+// We allow root and the Council to execute privileged collator selection operations.
+pub type CollatorSelectionUpdateOrigin = RootOrCouncil;
 
 impl pallet_collator_selection::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
@@ -559,7 +873,14 @@ impl pallet_collator_selection::Config for Runtime {
     type ValidatorId = <Self as frame_system::Config>::AccountId;
     type ValidatorIdOf = pallet_collator_selection::IdentityCollator;
     type ValidatorRegistration = Session;
-    type WeightInfo = ();
+    type WeightInfo = weights::pallet_collator_selection::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+    pub const XcmAppChangeDelay: BlockNumber = DAYS;
+    pub const XcmAppMaxTransactWeight: Weight = Weight::from_parts(10_000_000_000, 262_144);
+    pub const XcmAppMaxTransactCallLength: u32 = 2048;
+    pub const XcmAppTransactReportTimeout: BlockNumber = HOURS;
 }
 
 impl xcm_app::Config for Runtime {
@@ -567,6 +888,7 @@ impl xcm_app::Config for Runtime {
     type WeightInfo = xcm_app::weights::WeightInfo<Runtime>;
     type Balance = Balance;
     type OutboundChannel = SubstrateBridgeOutboundChannel;
+    type ManagerOrigin = RootOrCouncil;
     type AccountIdToMultiLocation = xcm_config::AccountIdToMultiLocation;
     type CallOrigin = dispatch::EnsureAccount<
         SubNetworkId,
@@ -576,14 +898,29 @@ impl xcm_app::Config for Runtime {
     type XcmTransfer = XTokens;
     type AccountIdConverter = sp_runtime::traits::Identity;
     type BalanceConverter = sp_runtime::traits::Identity;
+    type RuntimeCall = RuntimeCall;
+    type PalletsOrigin = OriginCaller;
+    type Scheduler = Scheduler;
+    type Preimages = Preimage;
+    type ChangeDelay = XcmAppChangeDelay;
+    type CancelOrigin = RootOrCouncil;
+    type LocalAssets = Tokens;
+    type XcmSender = xcm_config::XcmRouter;
+    type UniversalLocation = xcm_config::UniversalLocation;
+    type TransactQueryHandler = impls::XcmAppTransactQueries;
+    type ResponseOrigin = pallet_xcm::EnsureResponse<Everything>;
+    type MaxTransactWeight = XcmAppMaxTransactWeight;
+    type MaxTransactCallLength = XcmAppMaxTransactCallLength;
+    type TransactReportTimeout = XcmAppTransactReportTimeout;
 }
 
 impl xcm_app_sudo_wrapper::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = xcm_app_sudo_wrapper::weights::WeightInfo<Runtime>;
 }
 
 parameter_types! {
-    pub const SidechainRandomnessNetwork: SubNetworkId = SubNetworkId::Mainnet;
+    pub SidechainRandomnessNetwork: SubNetworkId = XCMApp::sora_network();
 }
 
 impl beefy_light_client::Config for Runtime {
@@ -612,8 +949,39 @@ impl dispatch::Config for Runtime {
     type CallFilter = SubstrateBridgeCallFilter;
 }
 
+parameter_types! {
+    /// Account SORA governance sends the bridge governance calls from, governance calls are
+    /// rejected until it's set
+    pub storage SoraGovernanceAccount: Option<AccountId> = None;
+}
+
+fn dispatch_governance_call(
+    call: BridgeGovernanceCall,
+) -> sp_runtime::DispatchResultWithInfo<PostDispatchInfo> {
+    use frame_support::traits::UnfilteredDispatchable;
+    let origin: RuntimeOrigin = frame_system::RawOrigin::Root.into();
+    match call {
+        BridgeGovernanceCall::AuthorizeUpgrade { code_hash } => {
+            cumulus_pallet_parachain_system::Call::<Runtime>::authorize_upgrade { code_hash }
+                .dispatch_bypass_filter(origin)
+        },
+        BridgeGovernanceCall::SetInvulnerables { invulnerables } => {
+            pallet_collator_selection::Call::<Runtime>::set_invulnerables { new: invulnerables }
+                .dispatch_bypass_filter(origin)
+        },
+        BridgeGovernanceCall::ForceDefaultXcmVersion { version } => {
+            pallet_xcm::Call::<Runtime>::force_default_xcm_version { maybe_xcm_version: version }
+                .dispatch_bypass_filter(origin)
+        },
+        BridgeGovernanceCall::ForceXcmVersion { location, version } => {
+            pallet_xcm::Call::<Runtime>::force_xcm_version { location, xcm_version: version }
+                .dispatch_bypass_filter(origin)
+        },
+    }
+}
+
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
-pub struct DispatchableSubstrateBridgeCall(bridge_types::substrate::BridgeCall);
+pub struct DispatchableSubstrateBridgeCall(pub ParachainBridgeCall);
 
 impl Dispatchable for DispatchableSubstrateBridgeCall {
     type RuntimeOrigin = crate::RuntimeOrigin;
@@ -626,30 +994,65 @@ impl Dispatchable for DispatchableSubstrateBridgeCall {
         origin: Self::RuntimeOrigin,
     ) -> sp_runtime::DispatchResultWithInfo<Self::PostInfo> {
         match self.0 {
-            bridge_types::substrate::BridgeCall::SubstrateApp(_msg) => Ok(().into()),
-            bridge_types::substrate::BridgeCall::XCMApp(msg) => {
+            ParachainBridgeCall::SubstrateApp(_msg) => Ok(().into()),
+            ParachainBridgeCall::XCMApp(msg) => {
                 let call: xcm_app::Call<crate::Runtime> = msg.into();
                 let call: crate::RuntimeCall = call.into();
                 call.dispatch(origin)
             },
-            bridge_types::substrate::BridgeCall::DataSigner(msg) => {
+            ParachainBridgeCall::DataSigner(msg) => {
                 let call: bridge_data_signer::Call<crate::Runtime> = msg.into();
                 let call: crate::RuntimeCall = call.into();
                 call.dispatch(origin)
             },
-            bridge_types::substrate::BridgeCall::MultisigVerifier(_) => Ok(().into()),
+            ParachainBridgeCall::MultisigVerifier(_) => Ok(().into()),
+            ParachainBridgeCall::Governance { sender, call } => {
+                // Governance calls are accepted only from the governance account of the
+                // configured SORA network
+                let res = <SubstrateBridgeCallOrigin as frame_support::traits::EnsureOrigin<
+                    RuntimeOrigin,
+                >>::ensure_origin(origin)?;
+                if res.network_id != XCMApp::sora_network()
+                    || SoraGovernanceAccount::get() != Some(sender)
+                {
+                    return Err(sp_runtime::DispatchError::BadOrigin.into());
+                }
+                dispatch_governance_call(call)
+            },
+            ParachainBridgeCall::XCMAppRemote(msg) => {
+                let call: xcm_app::Call<crate::Runtime> = msg.into();
+                let call: crate::RuntimeCall = call.into();
+                call.dispatch(origin)
+            },
+            ParachainBridgeCall::XCMAppTransactReport(_) => Ok(().into()),
         }
     }
 }
 
+pub type SubstrateBridgeCallOrigin = dispatch::EnsureAccount<
+    SubNetworkId,
+    (),
+    bridge_types::types::CallOriginOutput<SubNetworkId, H256, ()>,
+>;
+
 pub struct SubstrateBridgeCallFilter;
 impl Contains<DispatchableSubstrateBridgeCall> for SubstrateBridgeCallFilter {
     fn contains(call: &DispatchableSubstrateBridgeCall) -> bool {
         match &call.0 {
-            bridge_types::substrate::BridgeCall::SubstrateApp(_) => false,
-            bridge_types::substrate::BridgeCall::XCMApp(_) => true,
-            bridge_types::substrate::BridgeCall::DataSigner(_) => true,
-            bridge_types::substrate::BridgeCall::MultisigVerifier(_) => true,
+            ParachainBridgeCall::SubstrateApp(_) => false,
+            ParachainBridgeCall::XCMApp(_) => true,
+            ParachainBridgeCall::DataSigner(_) => true,
+            ParachainBridgeCall::MultisigVerifier(_) => true,
+            // Allow-list of root level calls SORA governance can dispatch
+            ParachainBridgeCall::Governance { call, .. } => match call {
+                BridgeGovernanceCall::AuthorizeUpgrade { .. } => true,
+                BridgeGovernanceCall::SetInvulnerables { .. } => true,
+                BridgeGovernanceCall::ForceDefaultXcmVersion { .. } => true,
+                BridgeGovernanceCall::ForceXcmVersion { .. } => true,
+            },
+            ParachainBridgeCall::XCMAppRemote(_) => true,
+            // Reports are sent to SORA only
+            ParachainBridgeCall::XCMAppTransactReport(_) => false,
         }
     }
 }
@@ -658,7 +1061,7 @@ impl substrate_bridge_channel::inbound::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type Verifier = MultisigVerifier;
     type MessageDispatch = SubstrateDispatch;
-    type WeightInfo = ();
+    type WeightInfo = weights::substrate_bridge_channel_inbound::WeightInfo<Runtime>;
     type UnsignedPriority = DataSignerPriority;
     type UnsignedLongevity = DataSignerLongevity;
     type MaxMessagePayloadSize = BridgeMaxMessagePayloadSize;
@@ -679,7 +1082,7 @@ impl substrate_bridge_channel::outbound::Config for Runtime {
     type MaxMessagePayloadSize = BridgeMaxMessagePayloadSize;
     type MaxMessagesPerCommit = BridgeMaxMessagesPerCommit;
     type AuxiliaryDigestHandler = LeafProvider;
-    type WeightInfo = ();
+    type WeightInfo = weights::substrate_bridge_channel_outbound::WeightInfo<Runtime>;
     type TimepointProvider = TimepointProvider;
     // Required for MessageStatusNotifier and actually not used
     type AssetId = ();
@@ -743,10 +1146,13 @@ construct_runtime!(
         Mmr: pallet_mmr = 4,
         Beefy: pallet_beefy = 5,
         BeefyMmr: pallet_beefy_mmr = 6,
+        MaintenanceMode: maintenance_mode::{Pallet, Call, Config, Storage, Event<T>} = 7,
 
         // Monetary stuff.
         Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>} = 10,
         TransactionPayment: pallet_transaction_payment::{Pallet, Storage, Event<T>} = 11,
+        Treasury: pallet_treasury::{Pallet, Call, Storage, Config, Event<T>} = 12,
+        AssetFeePayment: asset_fee_payment::{Pallet, Call, Storage, Event<T>} = 13,
 
         // Collator support. The order of these 4 are important and shall not change.
         Authorship: pallet_authorship::{Pallet, Storage} = 20,
@@ -757,16 +1163,32 @@ construct_runtime!(
 
         // XCM helpers.
         XcmpQueue: cumulus_pallet_xcmp_queue::{Pallet, Call, Storage, Event<T>} = 30,
-        PolkadotXcm: pallet_xcm::{Pallet, Event<T>, Origin, Config} = 31,
+        PolkadotXcm: pallet_xcm::{Pallet, Call, Event<T>, Origin, Config} = 31,
         CumulusXcm: cumulus_pallet_xcm::{Pallet, Event<T>, Origin} = 32,
         DmpQueue: cumulus_pallet_dmp_queue::{Pallet, Call, Storage, Event<T>} = 33,
+        HrmpManager: hrmp_manager::{Pallet, Call, Storage, Event<T>} = 34,
 
         // ORML
+        Tokens: orml_tokens::{Pallet, Call, Storage, Event<T>} = 40,
         XTokens: orml_xtokens::{Pallet, Storage, Event<T>} = 41,
 
+        // Governance
+        Council: pallet_collective::<Instance1>::{Pallet, Call, Storage, Origin<T>, Event<T>, Config<T>} = 50,
+        CouncilMembership: pallet_membership::<Instance1>::{Pallet, Call, Storage, Event<T>, Config<T>} = 51,
+        TechnicalCommittee: pallet_collective::<Instance2>::{Pallet, Call, Storage, Origin<T>, Event<T>, Config<T>} = 52,
+        TechnicalMembership: pallet_membership::<Instance2>::{Pallet, Call, Storage, Event<T>, Config<T>} = 53,
+        Scheduler: pallet_scheduler::{Pallet, Call, Storage, Event<T>} = 54,
+        Preimage: pallet_preimage::{Pallet, Call, Storage, Event<T>} = 55,
+        Whitelist: pallet_whitelist::{Pallet, Call, Storage, Event<T>} = 56,
+
+        // Operator accounts
+        Utility: pallet_utility::{Pallet, Call, Event} = 60,
+        Multisig: pallet_multisig::{Pallet, Call, Storage, Event<T>} = 61,
+        Proxy: pallet_proxy::{Pallet, Call, Storage, Event<T>} = 62,
+
         Sudo: pallet_sudo::{Pallet, Call, Storage, Event<T>, Config<T>} = 100,
 
-        XCMApp: xcm_app::{Pallet, Call, Storage, Event<T>} = 101,
+        XCMApp: xcm_app::{Pallet, Call, Storage, Event<T>, Config} = 101,
         BeefyLightClient: beefy_light_client::{Pallet, Call, Storage, Event<T>, Config} = 103,
         SubstrateBridgeInboundChannel: substrate_bridge_channel::inbound::{Pallet, Call, Storage, Event<T>, ValidateUnsigned} = 104,
         SubstrateBridgeOutboundChannel: substrate_bridge_channel::outbound::{Pallet, Config<T>, Storage, Event<T>} = 105,
@@ -788,11 +1210,31 @@ mod benches {
     define_benchmarks!(
         [frame_system, SystemBench::<Runtime>]
         [pallet_balances, Balances]
+        [pallet_treasury, Treasury]
         [pallet_session, SessionBench::<Runtime>]
         [pallet_timestamp, Timestamp]
         [pallet_collator_selection, CollatorSelection]
+        [pallet_collective, Council]
+        [pallet_membership, CouncilMembership]
+        [pallet_scheduler, Scheduler]
+        [pallet_preimage, Preimage]
+        [pallet_whitelist, Whitelist]
+        [pallet_utility, Utility]
+        [pallet_multisig, Multisig]
+        [pallet_proxy, Proxy]
         [cumulus_pallet_xcmp_queue, XcmpQueue]
-        [xcp_app, XCMApp]
+        [pallet_xcm, PolkadotXcm]
+        [pallet_xcm_benchmarks::fungible, XcmBalances]
+        [pallet_xcm_benchmarks::generic, XcmGeneric]
+        [xcm_app, XCMApp]
+        [xcm_app_sudo_wrapper, XCMAppSudoWrapper]
+        [maintenance_mode, MaintenanceMode]
+        [asset_fee_payment, AssetFeePayment]
+        [hrmp_manager, HrmpManager]
+        [substrate_bridge_channel::inbound, SubstrateBridgeInboundChannel]
+        [substrate_bridge_channel::outbound, SubstrateBridgeOutboundChannel]
+        [bridge_data_signer, BridgeDataSigner]
+        [multisig_verifier, MultisigVerifier]
     );
 }
 
@@ -969,6 +1411,39 @@ impl_runtime_apis! {
         }
     }
 
+    impl asset_fee_payment_runtime_api::AssetFeePaymentApi<Block, Balance, AssetId> for Runtime {
+        fn query_fee_in_asset(
+            uxt: <Block as BlockT>::Extrinsic,
+            len: u32,
+            asset_id: AssetId,
+        ) -> Option<Balance> {
+            let fee = TransactionPayment::query_info(uxt, len).partial_fee;
+            AssetFeePayment::convert_fee(asset_id, fee)
+        }
+        fn convert_fee(asset_id: AssetId, native_fee: Balance) -> Option<Balance> {
+            AssetFeePayment::convert_fee(asset_id, native_fee)
+        }
+        fn fee_rate(asset_id: AssetId) -> Option<sp_runtime::FixedU128> {
+            AssetFeePayment::fee_rate(asset_id)
+        }
+    }
+
+    impl xcm_app_runtime_api::XCMAppApi<Block, AccountId> for Runtime {
+        fn sora_account_id(network_id: SubNetworkId, account: sp_runtime::AccountId32) -> AccountId {
+            XCMApp::sora_account_id(network_id, account)
+        }
+    }
+
+    impl hrmp_manager_runtime_api::HrmpManagerApi<Block, AccountId, AssetId, Balance> for Runtime {
+        fn channels() -> Vec<hrmp_manager::ChannelInfo> {
+            HrmpManager::channels()
+        }
+        fn sovereign_accounts(
+        ) -> Vec<hrmp_manager_runtime_api::SovereignAccountInfo<AccountId, AssetId, Balance>> {
+            impls::reserve_sovereign_accounts()
+        }
+    }
+
     impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
         fn collect_collation_info(header: &<Block as BlockT>::Header) -> cumulus_primitives_core::CollationInfo {
             ParachainSystem::collect_collation_info(header)
@@ -994,13 +1469,16 @@ impl_runtime_apis! {
             Vec<frame_benchmarking::BenchmarkList>,
             Vec<frame_support::traits::StorageInfo>,
         ) {
-            use frame_benchmarking::{list_benchmark, Benchmarking, BenchmarkList};
+            use frame_benchmarking::{Benchmarking, BenchmarkList};
             use frame_support::traits::StorageInfoTrait;
             use frame_system_benchmarking::Pallet as SystemBench;
             use cumulus_pallet_session_benchmarking::Pallet as SessionBench;
 
+            type XcmBalances = pallet_xcm_benchmarks::fungible::Pallet::<Runtime>;
+            type XcmGeneric = pallet_xcm_benchmarks::generic::Pallet::<Runtime>;
+
             let mut list = Vec::<BenchmarkList>::new();
-            list_benchmark!(list, extra, xcm_app, XCMApp);
+            list_benchmarks!(list, extra);
 
             let storage_info = AllPalletsWithSystem::storage_info();
             return (list, storage_info)
@@ -1009,7 +1487,7 @@ impl_runtime_apis! {
         fn dispatch_benchmark(
             config: frame_benchmarking::BenchmarkConfig
         ) -> Result<Vec<frame_benchmarking::BenchmarkBatch>, sp_runtime::RuntimeString> {
-            use frame_benchmarking::{Benchmarking, BenchmarkBatch, TrackedStorageKey, add_benchmark};
+            use frame_benchmarking::{Benchmarking, BenchmarkBatch, TrackedStorageKey};
 
             use frame_system_benchmarking::Pallet as SystemBench;
             impl frame_system_benchmarking::Config for Runtime {}
@@ -1017,6 +1495,88 @@ impl_runtime_apis! {
             use cumulus_pallet_session_benchmarking::Pallet as SessionBench;
             impl cumulus_pallet_session_benchmarking::Config for Runtime {}
 
+            use frame_benchmarking::BenchmarkError;
+            use frame_support::traits::Get;
+            use xcm_config::{MaxAssetsIntoHolding, RelayLocation, SelfReserve};
+
+            impl pallet_xcm_benchmarks::Config for Runtime {
+                type XcmConfig = xcm_config::XcmConfig;
+                type AccountIdConverter = xcm_config::LocationToAccountId;
+                fn valid_destination() -> Result<MultiLocation, BenchmarkError> {
+                    Ok(RelayLocation::get())
+                }
+                fn worst_case_holding(_depositable_count: u32) -> MultiAssets {
+                    // A mix of fungible assets from distinct reserves to fill up the holding.
+                    (0..MaxAssetsIntoHolding::get())
+                        .map(|i| MultiAsset {
+                            id: Concrete(MultiLocation::new(
+                                1,
+                                X2(Parachain(i), GeneralIndex(i as u128)),
+                            )),
+                            fun: Fungible(UNIT),
+                        })
+                        .chain(core::iter::once(MultiAsset {
+                            id: Concrete(RelayLocation::get()),
+                            fun: Fungible(u128::MAX),
+                        }))
+                        .collect::<Vec<_>>()
+                        .into()
+                }
+            }
+
+            parameter_types! {
+                pub const TrustedTeleporter: Option<(MultiLocation, MultiAsset)> = None;
+                pub const CheckedAccount: Option<(AccountId, xcm_builder::MintLocation)> = None;
+            }
+
+            impl pallet_xcm_benchmarks::fungible::Config for Runtime {
+                type TransactAsset = Balances;
+                type CheckedAccount = CheckedAccount;
+                type TrustedTeleporter = TrustedTeleporter;
+                fn get_multi_asset() -> MultiAsset {
+                    // The native token, transacted by `CurrencyTransactor` through `Balances`
+                    MultiAsset { id: Concrete(SelfReserve::get()), fun: Fungible(UNIT) }
+                }
+            }
+
+            impl pallet_xcm_benchmarks::generic::Config for Runtime {
+                type RuntimeCall = RuntimeCall;
+                fn worst_case_response() -> (u64, Response) {
+                    (0u64, Response::Version(Default::default()))
+                }
+                fn worst_case_asset_exchange(
+                ) -> Result<(MultiAssets, MultiAssets), BenchmarkError> {
+                    Err(BenchmarkError::Skip)
+                }
+                fn universal_alias() -> Result<Junction, BenchmarkError> {
+                    Err(BenchmarkError::Skip)
+                }
+                fn transact_origin_and_runtime_call(
+                ) -> Result<(MultiLocation, RuntimeCall), BenchmarkError> {
+                    Ok((
+                        RelayLocation::get(),
+                        frame_system::Call::remark_with_event { remark: vec![] }.into(),
+                    ))
+                }
+                fn subscribe_origin() -> Result<MultiLocation, BenchmarkError> {
+                    Ok(RelayLocation::get())
+                }
+                fn claimable_asset(
+                ) -> Result<(MultiLocation, MultiLocation, MultiAssets), BenchmarkError> {
+                    let origin = RelayLocation::get();
+                    let assets: MultiAssets = (Concrete(RelayLocation::get()), 1_000 * UNIT).into();
+                    let ticket = MultiLocation { parents: 0, interior: Here };
+                    Ok((origin, ticket, assets))
+                }
+                fn unlockable_asset(
+                ) -> Result<(MultiLocation, MultiLocation, MultiAsset), BenchmarkError> {
+                    Err(BenchmarkError::Skip)
+                }
+            }
+
+            type XcmBalances = pallet_xcm_benchmarks::fungible::Pallet::<Runtime>;
+            type XcmGeneric = pallet_xcm_benchmarks::generic::Pallet::<Runtime>;
+
             let whitelist: Vec<TrackedStorageKey> = vec![
                 // Block Number
                 hex_literal::hex!("26aa394eea5630e07c48ae0c9558cef702a5c1b19ab7a04f536c519aca4983ac").to_vec().into(),
@@ -1032,7 +1592,7 @@ impl_runtime_apis! {
 
             let mut batches = Vec::<BenchmarkBatch>::new();
             let params = (&config, &whitelist);
-            add_benchmark!(params, batches, xcm_app, XCMApp);
+            add_benchmarks!(params, batches);
 
             if batches.is_empty() { return Err("Benchmark not found for this pallet.".into()) }
             Ok(batches)
@@ -0,0 +1,137 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use crate::{AllPalletsWithSystem, BlockNumber, DmpQueue, RuntimeCall, RuntimeOrigin, XcmpQueue};
+use cumulus_primitives_core::{relay_chain::BlockNumber as RelayBlockNumber, DmpMessageHandler};
+use frame_support::{
+    dispatch::DispatchResult,
+    traits::{Contains, OffchainWorker, OnFinalize, OnIdle, OnInitialize, OnRuntimeUpgrade},
+    weights::Weight,
+};
+use sp_std::prelude::*;
+
+/// Calls allowed in maintenance mode: inherents, session keys and governance
+pub struct MaintenanceFilter;
+impl Contains<RuntimeCall> for MaintenanceFilter {
+    fn contains(c: &RuntimeCall) -> bool {
+        matches!(
+            c,
+            RuntimeCall::System(..)
+                | RuntimeCall::ParachainSystem(..)
+                | RuntimeCall::Timestamp(..)
+                | RuntimeCall::Session(..)
+                | RuntimeCall::Sudo(..)
+                | RuntimeCall::Council(..)
+                | RuntimeCall::CouncilMembership(..)
+                | RuntimeCall::TechnicalCommittee(..)
+                | RuntimeCall::TechnicalMembership(..)
+                | RuntimeCall::Scheduler(..)
+                | RuntimeCall::Preimage(..)
+                | RuntimeCall::Whitelist(..)
+                | RuntimeCall::MaintenanceMode(..)
+        )
+    }
+}
+
+/// Puts the downward messages to the `DmpQueue` without executing them
+pub struct MaintenanceDmpHandler;
+impl DmpMessageHandler for MaintenanceDmpHandler {
+    fn handle_dmp_messages(
+        iter: impl Iterator<Item = (RelayBlockNumber, Vec<u8>)>,
+        _limit: Weight,
+    ) -> Weight {
+        DmpQueue::handle_dmp_messages(iter, Weight::zero())
+    }
+}
+
+pub struct XcmExecutionManager;
+impl maintenance_mode::PauseXcmExecution for XcmExecutionManager {
+    fn suspend_xcm_execution() -> DispatchResult {
+        XcmpQueue::suspend_xcm_execution(RuntimeOrigin::root())
+    }
+
+    fn resume_xcm_execution() -> DispatchResult {
+        XcmpQueue::resume_xcm_execution(RuntimeOrigin::root())
+    }
+}
+
+/// Pallet hooks executed in maintenance mode, `on_idle` is skipped so that
+/// the queued XCM messages are not serviced
+pub struct MaintenanceHooks;
+
+impl OnInitialize<BlockNumber> for MaintenanceHooks {
+    fn on_initialize(n: BlockNumber) -> Weight {
+        AllPalletsWithSystem::on_initialize(n)
+    }
+}
+
+impl OnIdle<BlockNumber> for MaintenanceHooks {
+    fn on_idle(_n: BlockNumber, _max_weight: Weight) -> Weight {
+        Weight::zero()
+    }
+}
+
+impl OnRuntimeUpgrade for MaintenanceHooks {
+    fn on_runtime_upgrade() -> Weight {
+        AllPalletsWithSystem::on_runtime_upgrade()
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+        AllPalletsWithSystem::pre_upgrade()
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+        AllPalletsWithSystem::post_upgrade(state)
+    }
+}
+
+impl OnFinalize<BlockNumber> for MaintenanceHooks {
+    fn on_finalize(n: BlockNumber) {
+        AllPalletsWithSystem::on_finalize(n)
+    }
+}
+
+impl OffchainWorker<BlockNumber> for MaintenanceHooks {
+    fn offchain_worker(n: BlockNumber) {
+        AllPalletsWithSystem::offchain_worker(n)
+    }
+}
+
+#[cfg(feature = "try-runtime")]
+impl frame_support::traits::TryState<BlockNumber> for MaintenanceHooks {
+    fn try_state(
+        n: BlockNumber,
+        targets: frame_support::traits::TryStateSelect,
+    ) -> Result<(), &'static str> {
+        AllPalletsWithSystem::try_state(n, targets)
+    }
+}
@@ -39,6 +39,8 @@ use crate::{AccountId, Aura, BeefyId, RuntimeBlockWeights, Session};
 pub type Migrations = (
     pallet_xcm::migration::v1::MigrateToV1<Runtime>,
     pallet_balances::migration::MigrateManyToTrackInactive<Runtime, EmptyAccountList>,
+    xcm_app::migrations::v1::MigrateToV1<Runtime>,
+    SetXcmAppNetworks,
 );
 
 impl_opaque_keys! {
@@ -71,6 +73,70 @@ impl OnRuntimeUpgrade for SessionKeysMigration {
         RuntimeBlockWeights::get().max_block
     }
 }
+/// Removes the sudo key when the Council is formed, after that root calls can be dispatched
+/// only by governance through `Whitelist`.
+///
+/// Should be added to [`Migrations`] once Council and Technical Committee members are set.
+/// `Sudo` pallet can be removed from `construct_runtime!` in the following runtime upgrade.
+pub struct RemoveSudo;
+
+impl OnRuntimeUpgrade for RemoveSudo {
+    fn on_runtime_upgrade() -> Weight {
+        let db_weight = <Runtime as frame_system::Config>::DbWeight::get();
+        if Council::members().is_empty() || TechnicalCommittee::members().is_empty() {
+            log::warn!("Council or Technical Committee has no members, sudo key is kept");
+            return db_weight.reads(2);
+        }
+        let prefix = sp_io::hashing::twox_128(
+            <Sudo as frame_support::traits::PalletInfoAccess>::name().as_bytes(),
+        );
+        let res = frame_support::storage::unhashed::clear_prefix(&prefix, None, None);
+        log::info!("Sudo storage removed, {} keys", res.unique);
+        db_weight.reads_writes(2 + res.loops as u64, res.unique as u64)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
+        if !Council::members().is_empty()
+            && !TechnicalCommittee::members().is_empty()
+            && Sudo::key().is_some()
+        {
+            return Err("Sudo key is not removed");
+        }
+        Ok(())
+    }
+}
+
+/// Sets the relay and SORA networks of `xcm_app` for the live chains, which were started
+/// before the networks were stored and would read the Rococo default otherwise.
+pub struct SetXcmAppNetworks;
+
+impl OnRuntimeUpgrade for SetXcmAppNetworks {
+    fn on_runtime_upgrade() -> Weight {
+        let db_weight = <Runtime as frame_system::Config>::DbWeight::get();
+        let mut writes = 0;
+        if !xcm_app::RelayNetwork::<Runtime>::exists() {
+            xcm_app::RelayNetwork::<Runtime>::put(variant::RELAY_NETWORK);
+            writes += 1;
+        }
+        if !xcm_app::SoraNetwork::<Runtime>::exists() {
+            xcm_app::SoraNetwork::<Runtime>::put(variant::SORA_NETWORK);
+            writes += 1;
+        }
+        log::info!("xcm_app networks set, {} values written", writes);
+        db_weight.reads_writes(2, writes)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
+        if !xcm_app::RelayNetwork::<Runtime>::exists() || !xcm_app::SoraNetwork::<Runtime>::exists()
+        {
+            return Err("xcm_app networks are not set");
+        }
+        Ok(())
+    }
+}
+
 pub struct EmptyAccountList;
 
 impl sp_core::Get<Vec<AccountId>> for EmptyAccountList {
@@ -0,0 +1,202 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use crate::RuntimeCall;
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::traits::InstanceFilter;
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// The type used to represent the kinds of proxying allowed.
+#[derive(
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Encode,
+    Decode,
+    RuntimeDebug,
+    MaxEncodedLen,
+    TypeInfo,
+)]
+pub enum ProxyType {
+    /// All calls
+    Any,
+    /// All calls except balance and asset transfers
+    NonTransfer,
+    /// Bridge transfers of the assets held on this parachain. The other calls of `xcm_app` and
+    /// `xcm_app_sudo_wrapper` require the manager or the bridge origin, which a proxied
+    /// signed origin never has, so they are left out.
+    XcmAppOperator,
+    /// Session keys and collator candidacy management
+    Collator,
+}
+
+impl Default for ProxyType {
+    fn default() -> Self {
+        Self::Any
+    }
+}
+
+/// Calls sending the assets of the caller through the bridge
+fn is_bridge_transfer(c: &RuntimeCall) -> bool {
+    matches!(
+        c,
+        RuntimeCall::XCMApp(
+            xcm_app::Call::send_to_sora { .. } | xcm_app::Call::send_to_parachain { .. }
+        )
+    )
+}
+
+/// Calls moving the funds of the caller
+fn is_transfer(c: &RuntimeCall) -> bool {
+    matches!(c, RuntimeCall::Balances(..) | RuntimeCall::Tokens(..) | RuntimeCall::PolkadotXcm(..))
+        || is_bridge_transfer(c)
+}
+
+impl InstanceFilter<RuntimeCall> for ProxyType {
+    fn filter(&self, c: &RuntimeCall) -> bool {
+        match self {
+            ProxyType::Any => true,
+            ProxyType::NonTransfer => !is_transfer(c),
+            ProxyType::XcmAppOperator => {
+                is_bridge_transfer(c) || matches!(c, RuntimeCall::Utility(..))
+            },
+            ProxyType::Collator => matches!(
+                c,
+                RuntimeCall::Session(..)
+                    | RuntimeCall::CollatorSelection(..)
+                    | RuntimeCall::Utility(..)
+            ),
+        }
+    }
+
+    fn is_superset(&self, o: &Self) -> bool {
+        match (self, o) {
+            (x, y) if x == y => true,
+            (ProxyType::Any, _) => true,
+            (_, ProxyType::Any) => false,
+            (ProxyType::NonTransfer, ProxyType::Collator) => true,
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AccountId;
+
+    fn transfer_call() -> RuntimeCall {
+        RuntimeCall::Balances(pallet_balances::Call::transfer {
+            dest: AccountId::new([1; 32]).into(),
+            value: 1,
+        })
+    }
+
+    fn xcm_app_call() -> RuntimeCall {
+        RuntimeCall::XCMApp(xcm_app::Call::cancel_change { change_id: 0 })
+    }
+
+    fn send_to_sora_call() -> RuntimeCall {
+        RuntimeCall::XCMApp(xcm_app::Call::send_to_sora { asset_id: [1; 32].into(), amount: 1 })
+    }
+
+    fn sudo_wrapper_call() -> RuntimeCall {
+        RuntimeCall::XCMAppSudoWrapper(xcm_app_sudo_wrapper::Call::delete_mapping {
+            asset_id: [1; 32].into(),
+        })
+    }
+
+    fn collator_call() -> RuntimeCall {
+        RuntimeCall::CollatorSelection(pallet_collator_selection::Call::register_as_candidate {})
+    }
+
+    fn session_call() -> RuntimeCall {
+        RuntimeCall::Session(pallet_session::Call::purge_keys {})
+    }
+
+    fn batch(calls: Vec<RuntimeCall>) -> RuntimeCall {
+        RuntimeCall::Utility(pallet_utility::Call::batch { calls })
+    }
+
+    #[test]
+    fn any_allows_everything() {
+        for call in [transfer_call(), xcm_app_call(), collator_call(), session_call()] {
+            assert!(ProxyType::Any.filter(&call));
+        }
+    }
+
+    #[test]
+    fn non_transfer_forbids_balances() {
+        assert!(!ProxyType::NonTransfer.filter(&transfer_call()));
+        assert!(!ProxyType::NonTransfer.filter(&send_to_sora_call()));
+        for call in [xcm_app_call(), sudo_wrapper_call(), collator_call(), session_call()] {
+            assert!(ProxyType::NonTransfer.filter(&call));
+        }
+    }
+
+    #[test]
+    fn xcm_app_operator_is_limited_to_bridge_transfers() {
+        assert!(ProxyType::XcmAppOperator.filter(&send_to_sora_call()));
+        assert!(ProxyType::XcmAppOperator.filter(&batch(vec![send_to_sora_call()])));
+        for call in
+            [transfer_call(), xcm_app_call(), sudo_wrapper_call(), collator_call(), session_call()]
+        {
+            assert!(!ProxyType::XcmAppOperator.filter(&call));
+        }
+    }
+
+    #[test]
+    fn collator_is_limited_to_session_and_collator_selection() {
+        assert!(ProxyType::Collator.filter(&collator_call()));
+        assert!(ProxyType::Collator.filter(&session_call()));
+        for call in [transfer_call(), xcm_app_call(), sudo_wrapper_call()] {
+            assert!(!ProxyType::Collator.filter(&call));
+        }
+    }
+
+    #[test]
+    fn superset_relations() {
+        use ProxyType::*;
+        for proxy_type in [Any, NonTransfer, XcmAppOperator, Collator] {
+            assert!(Any.is_superset(&proxy_type));
+            assert!(proxy_type.is_superset(&proxy_type));
+        }
+        assert!(!NonTransfer.is_superset(&XcmAppOperator));
+        assert!(NonTransfer.is_superset(&Collator));
+        assert!(!NonTransfer.is_superset(&Any));
+        assert!(!XcmAppOperator.is_superset(&Collator));
+        assert!(!Collator.is_superset(&XcmAppOperator));
+        assert!(!Collator.is_superset(&NonTransfer));
+    }
+}
@@ -29,19 +29,29 @@
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use sp_runtime::traits::Zero;
-use sp_std::prelude::*;
+use sp_std::{marker::PhantomData, prelude::*};
 use xcm::{latest::Weight as XcmWeight, prelude::*};
+use xcm_builder::TakeRevenue;
 use xcm_executor::{traits::WeightTrader, Assets};
 
-pub struct ParachainTrader {
+/// Charges the weight in the asset of the payment, the charged amount is passed
+/// to `R` when the trader is dropped
+pub struct ParachainTrader<R: TakeRevenue> {
     pub weight: XcmWeight,
     multi_location: Option<MultiLocation>,
+    charged: u128,
+    _revenue: PhantomData<R>,
 }
 
-impl WeightTrader for ParachainTrader {
+impl<R: TakeRevenue> WeightTrader for ParachainTrader<R> {
     fn new() -> Self {
         log::trace!(target: "xcm::weight", "creating new WeightTrader instance");
-        Self { weight: XcmWeight::zero(), multi_location: None }
+        Self {
+            weight: XcmWeight::zero(),
+            multi_location: None,
+            charged: Zero::zero(),
+            _revenue: PhantomData,
+        }
     }
 
     fn buy_weight(&mut self, weight: XcmWeight, payment: Assets) -> Result<Assets, XcmError> {
@@ -61,6 +71,7 @@ impl WeightTrader for ParachainTrader {
         }
 
         let unused = payment.checked_sub(required).map_err(|_| XcmError::TooExpensive)?;
+        self.charged = self.charged.saturating_add(weight.ref_time() as u128);
         Ok(unused)
     }
 
@@ -75,9 +86,20 @@ impl WeightTrader for ParachainTrader {
                 if weight.is_zero() {
                     None
                 } else {
+                    self.charged = self.charged.saturating_sub(weight.ref_time() as u128);
                     Some((ml.clone(), weight.ref_time() as u128).into())
                 }
             },
         }
     }
 }
+
+impl<R: TakeRevenue> Drop for ParachainTrader<R> {
+    fn drop(&mut self) {
+        if let Some(ml) = self.multi_location.take() {
+            if !self.charged.is_zero() {
+                R::take_revenue((ml, self.charged).into());
+            }
+        }
+    }
+}
@@ -0,0 +1,85 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Constants which differ between the Kusama, Polkadot and Rococo builds of the runtime.
+//!
+//! The variant is selected with the `kusama`, `polkadot` or `rococo` cargo feature,
+//! Kusama runtime is built when none of them is enabled.
+
+use bridge_types::SubNetworkId;
+use xcm::v3::NetworkId;
+
+#[cfg(any(
+    all(feature = "kusama", feature = "polkadot"),
+    all(feature = "kusama", feature = "rococo"),
+    all(feature = "polkadot", feature = "rococo"),
+))]
+compile_error!("Only one of `kusama`, `polkadot` and `rococo` features can be enabled");
+
+#[cfg(not(any(feature = "polkadot", feature = "rococo")))]
+mod consts {
+    use super::*;
+
+    /// Parachain id used where `ParachainInfo` is not available (metadata generation)
+    pub const PARA_ID: u32 = 2011;
+    pub const SS58_PREFIX: u16 = 420;
+    /// Relay chain network the runtime is built for
+    pub const RELAY_NETWORK: NetworkId = NetworkId::Kusama;
+    /// SORA network the bridge messages are sent to
+    pub const SORA_NETWORK: SubNetworkId = SubNetworkId::Mainnet;
+}
+
+#[cfg(feature = "polkadot")]
+mod consts {
+    use super::*;
+
+    /// Parachain id used where `ParachainInfo` is not available (metadata generation)
+    pub const PARA_ID: u32 = 2025;
+    pub const SS58_PREFIX: u16 = 81;
+    /// Relay chain network the runtime is built for
+    pub const RELAY_NETWORK: NetworkId = NetworkId::Polkadot;
+    /// SORA network the bridge messages are sent to
+    pub const SORA_NETWORK: SubNetworkId = SubNetworkId::Mainnet;
+}
+
+#[cfg(feature = "rococo")]
+mod consts {
+    use super::*;
+
+    /// Parachain id used where `ParachainInfo` is not available (metadata generation)
+    pub const PARA_ID: u32 = 2011;
+    pub const SS58_PREFIX: u16 = 420;
+    /// Relay chain network the runtime is built for
+    pub const RELAY_NETWORK: NetworkId = NetworkId::Rococo;
+    /// SORA network the bridge messages are sent to
+    pub const SORA_NETWORK: SubNetworkId = SubNetworkId::Mainnet;
+}
+
+pub use consts::*;
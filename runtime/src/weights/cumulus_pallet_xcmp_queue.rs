@@ -0,0 +1,51 @@
+
+//! Weights for `cumulus_pallet_xcmp_queue`
+//!
+//! NOT GENERATED: the values are estimated by hand from the storage accesses of the calls
+//! and have not been measured. Regenerate them on the reference hardware with
+//! `./housekeeping/benchmark.sh cumulus_pallet_xcmp_queue` before relying on them.
+
+// Command to generate:
+// ./target/release/parachain-collator
+// benchmark
+// pallet
+// --chain
+// dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --pallet
+// cumulus_pallet_xcmp_queue
+// --extrinsic
+// *
+// --steps
+// 50
+// --repeat
+// 20
+// --output
+// runtime/src/weights/cumulus_pallet_xcmp_queue.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `cumulus_pallet_xcmp_queue`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> cumulus_pallet_xcmp_queue::WeightInfo for WeightInfo<T> {
+	// Storage: XcmpQueue QueueConfig (r:1 w:1)
+	fn set_config_with_u32() -> Weight {
+		// Minimum execution time: 5_487 nanoseconds.
+		Weight::from_ref_time(5_716_000)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: XcmpQueue QueueConfig (r:1 w:1)
+	fn set_config_with_weight() -> Weight {
+		// Minimum execution time: 5_406 nanoseconds.
+		Weight::from_ref_time(5_632_000)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+}
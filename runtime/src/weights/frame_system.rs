@@ -0,0 +1,85 @@
+
+//! Weights for `frame_system`
+//!
+//! NOT GENERATED: the values are estimated by hand from the storage accesses of the calls
+//! and have not been measured. Regenerate them on the reference hardware with
+//! `./housekeeping/benchmark.sh frame_system` before relying on them.
+
+// Command to generate:
+// ./target/release/parachain-collator
+// benchmark
+// pallet
+// --chain
+// dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --pallet
+// frame_system
+// --extrinsic
+// *
+// --steps
+// 50
+// --repeat
+// 20
+// --output
+// runtime/src/weights/frame_system.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `frame_system`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> frame_system::WeightInfo for WeightInfo<T> {
+	/// The range of component `b` is `[0, 3932160]`.
+	fn remark(b: u32) -> Weight {
+		// Minimum execution time: 2_364 nanoseconds.
+		Weight::from_ref_time(2_463_000)
+			// Standard Error: 0
+			.saturating_add(Weight::from_ref_time(411).saturating_mul(b.into()))
+	}
+	/// The range of component `b` is `[0, 3932160]`.
+	fn remark_with_event(b: u32) -> Weight {
+		// Minimum execution time: 7_409 nanoseconds.
+		Weight::from_ref_time(7_718_000)
+			// Standard Error: 0
+			.saturating_add(Weight::from_ref_time(1_766).saturating_mul(b.into()))
+	}
+	// Storage: unknown `0x3a686561707061676573` (r:0 w:1)
+	fn set_heap_pages() -> Weight {
+		// Minimum execution time: 4_716 nanoseconds.
+		Weight::from_ref_time(4_913_000)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: Skipped Metadata (r:0 w:0)
+	/// The range of component `i` is `[0, 1000]`.
+	fn set_storage(i: u32) -> Weight {
+		// Minimum execution time: 2_438 nanoseconds.
+		Weight::from_ref_time(2_540_000)
+			// Standard Error: 1_964
+			.saturating_add(Weight::from_ref_time(712_000).saturating_mul(i.into()))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(i.into())))
+	}
+	// Storage: Skipped Metadata (r:0 w:0)
+	/// The range of component `i` is `[0, 1000]`.
+	fn kill_storage(i: u32) -> Weight {
+		// Minimum execution time: 2_372 nanoseconds.
+		Weight::from_ref_time(2_471_000)
+			// Standard Error: 1_427
+			.saturating_add(Weight::from_ref_time(557_316).saturating_mul(i.into()))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(i.into())))
+	}
+	// Storage: Skipped Metadata (r:0 w:0)
+	/// The range of component `p` is `[0, 1000]`.
+	fn kill_prefix(p: u32) -> Weight {
+		// Minimum execution time: 4_638 nanoseconds.
+		Weight::from_ref_time(4_832_000)
+			// Standard Error: 2_860
+			.saturating_add(Weight::from_ref_time(1_176_449).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
+	}
+}
@@ -0,0 +1,72 @@
+//! Weights for `orml_tokens`
+//!
+//! NOT GENERATED: the values are estimated by hand from the storage accesses of the calls
+//! and have not been measured. Regenerate them on the reference hardware with the command
+//! below before relying on them.
+
+// Command to generate:
+// ./target/release/parachain-collator
+// benchmark
+// pallet
+// --chain
+// dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --pallet
+// orml_tokens
+// --extrinsic
+// *
+// --steps
+// 50
+// --repeat
+// 20
+// --output
+// runtime/src/weights/orml_tokens.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `orml_tokens`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> orml_tokens::WeightInfo for WeightInfo<T> {
+	// Storage: Tokens Accounts (r:2 w:2)
+	// Storage: System Account (r:1 w:1)
+	fn transfer() -> Weight {
+		Weight::from_ref_time(45_000_000)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: Tokens Accounts (r:2 w:2)
+	// Storage: System Account (r:2 w:2)
+	fn transfer_all() -> Weight {
+		Weight::from_ref_time(50_000_000)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	// Storage: Tokens Accounts (r:2 w:2)
+	// Storage: System Account (r:1 w:1)
+	fn transfer_keep_alive() -> Weight {
+		Weight::from_ref_time(45_000_000)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: Tokens Accounts (r:2 w:2)
+	// Storage: System Account (r:2 w:2)
+	fn force_transfer() -> Weight {
+		Weight::from_ref_time(50_000_000)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	fn set_balance() -> Weight {
+		Weight::from_ref_time(35_000_000)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+}
@@ -0,0 +1,86 @@
+
+//! Weights for `pallet_balances`
+//!
+//! NOT GENERATED: the values are estimated by hand from the storage accesses of the calls
+//! and have not been measured. Regenerate them on the reference hardware with
+//! `./housekeeping/benchmark.sh pallet_balances` before relying on them.
+
+// Command to generate:
+// ./target/release/parachain-collator
+// benchmark
+// pallet
+// --chain
+// dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --pallet
+// pallet_balances
+// --extrinsic
+// *
+// --steps
+// 50
+// --repeat
+// 20
+// --output
+// runtime/src/weights/pallet_balances.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_balances`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_balances::WeightInfo for WeightInfo<T> {
+	// Storage: System Account (r:1 w:1)
+	fn transfer() -> Weight {
+		// Minimum execution time: 39_952 nanoseconds.
+		Weight::from_ref_time(41_617_000)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: System Account (r:1 w:1)
+	fn transfer_keep_alive() -> Weight {
+		// Minimum execution time: 29_956 nanoseconds.
+		Weight::from_ref_time(31_205_000)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: System Account (r:1 w:1)
+	fn set_balance_creating() -> Weight {
+		// Minimum execution time: 22_026 nanoseconds.
+		Weight::from_ref_time(22_944_000)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: System Account (r:1 w:1)
+	fn set_balance_killing() -> Weight {
+		// Minimum execution time: 24_681 nanoseconds.
+		Weight::from_ref_time(25_710_000)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: System Account (r:2 w:2)
+	fn force_transfer() -> Weight {
+		// Minimum execution time: 41_164 nanoseconds.
+		Weight::from_ref_time(42_880_000)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: System Account (r:1 w:1)
+	fn transfer_all() -> Weight {
+		// Minimum execution time: 36_010 nanoseconds.
+		Weight::from_ref_time(37_511_000)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: System Account (r:1 w:1)
+	fn force_unreserve() -> Weight {
+		// Minimum execution time: 19_109 nanoseconds.
+		Weight::from_ref_time(19_906_000)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+}
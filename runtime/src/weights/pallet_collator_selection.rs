@@ -0,0 +1,112 @@
+
+//! Weights for `pallet_collator_selection`
+//!
+//! NOT GENERATED: the values are estimated by hand from the storage accesses of the calls
+//! and have not been measured. Regenerate them on the reference hardware with
+//! `./housekeeping/benchmark.sh pallet_collator_selection` before relying on them.
+
+// Command to generate:
+// ./target/release/parachain-collator
+// benchmark
+// pallet
+// --chain
+// dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --pallet
+// pallet_collator_selection
+// --extrinsic
+// *
+// --steps
+// 50
+// --repeat
+// 20
+// --output
+// runtime/src/weights/pallet_collator_selection.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_collator_selection`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_collator_selection::WeightInfo for WeightInfo<T> {
+	// Storage: Session NextKeys (r:1 w:0)
+	// Storage: CollatorSelection Invulnerables (r:0 w:1)
+	/// The range of component `b` is `[1, 100]`.
+	fn set_invulnerables(b: u32) -> Weight {
+		// Minimum execution time: 13_694 nanoseconds.
+		Weight::from_ref_time(14_265_000)
+			// Standard Error: 7_032
+			.saturating_add(Weight::from_ref_time(2_801_514).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: CollatorSelection DesiredCandidates (r:0 w:1)
+	fn set_desired_candidates() -> Weight {
+		// Minimum execution time: 7_982 nanoseconds.
+		Weight::from_ref_time(8_315_000)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: CollatorSelection CandidacyBond (r:0 w:1)
+	fn set_candidacy_bond() -> Weight {
+		// Minimum execution time: 8_323 nanoseconds.
+		Weight::from_ref_time(8_670_000)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: CollatorSelection Candidates (r:1 w:1)
+	// Storage: CollatorSelection DesiredCandidates (r:1 w:0)
+	// Storage: CollatorSelection Invulnerables (r:1 w:0)
+	// Storage: Session NextKeys (r:1 w:0)
+	// Storage: CollatorSelection CandidacyBond (r:1 w:0)
+	// Storage: CollatorSelection LastAuthoredBlock (r:0 w:1)
+	/// The range of component `c` is `[1, 999]`.
+	fn register_as_candidate(c: u32) -> Weight {
+		// Minimum execution time: 34_679 nanoseconds.
+		Weight::from_ref_time(36_124_000)
+			// Standard Error: 1_203
+			.saturating_add(Weight::from_ref_time(100_527).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: CollatorSelection Candidates (r:1 w:1)
+	// Storage: CollatorSelection LastAuthoredBlock (r:0 w:1)
+	/// The range of component `c` is `[6, 1000]`.
+	fn leave_intent(c: u32) -> Weight {
+		// Minimum execution time: 26_830 nanoseconds.
+		Weight::from_ref_time(27_948_000)
+			// Standard Error: 1_158
+			.saturating_add(Weight::from_ref_time(103_244).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: System Account (r:2 w:2)
+	// Storage: CollatorSelection LastAuthoredBlock (r:0 w:1)
+	fn note_author() -> Weight {
+		// Minimum execution time: 29_848 nanoseconds.
+		Weight::from_ref_time(31_092_000)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: CollatorSelection Candidates (r:1 w:0)
+	// Storage: CollatorSelection LastAuthoredBlock (r:999 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: CollatorSelection Invulnerables (r:1 w:0)
+	// Storage: System BlockWeight (r:1 w:1)
+	/// The range of component `r` is `[1, 1000]`.
+	/// The range of component `c` is `[1, 1000]`.
+	fn new_session(r: u32, c: u32) -> Weight {
+		// Minimum execution time: 16_656 nanoseconds.
+		Weight::from_ref_time(17_350_000)
+			// Standard Error: 112_404
+			.saturating_add(Weight::from_ref_time(12_470).saturating_mul(r.into()))
+			// Standard Error: 112_404
+			.saturating_add(Weight::from_ref_time(11_806_420).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+	}
+}
@@ -0,0 +1,128 @@
+
+//! Weights for `pallet_membership`
+//!
+//! NOT GENERATED: the values are estimated by hand from the storage accesses of the calls
+//! and have not been measured. Regenerate them on the reference hardware with the command
+//! below before relying on them.
+
+// Command to generate:
+// ./target/release/parachain-collator
+// benchmark
+// pallet
+// --chain
+// dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --pallet
+// pallet_membership
+// --extrinsic
+// *
+// --steps
+// 50
+// --repeat
+// 20
+// --output
+// runtime/src/weights/pallet_membership.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_membership`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_membership::WeightInfo for WeightInfo<T> {
+	// Storage: CouncilMembership Members (r:1 w:1)
+	// Storage: Council Proposals (r:1 w:0)
+	// Storage: Council Members (r:0 w:1)
+	// Storage: Council Prime (r:0 w:1)
+	/// The range of component `m` is `[1, 99]`.
+	fn add_member(m: u32) -> Weight {
+		// Minimum execution time: 17_801 nanoseconds.
+		Weight::from_ref_time(17_044_583)
+			// Standard Error: 4_921
+			.saturating_add(Weight::from_ref_time(49_210).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: CouncilMembership Members (r:1 w:1)
+	// Storage: Council Proposals (r:1 w:0)
+	// Storage: CouncilMembership Prime (r:1 w:0)
+	// Storage: Council Members (r:0 w:1)
+	// Storage: Council Prime (r:0 w:1)
+	/// The range of component `m` is `[2, 100]`.
+	fn remove_member(m: u32) -> Weight {
+		// Minimum execution time: 20_617 nanoseconds.
+		Weight::from_ref_time(19_918_441)
+			// Standard Error: 4_510
+			.saturating_add(Weight::from_ref_time(45_108).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: CouncilMembership Members (r:1 w:1)
+	// Storage: Council Proposals (r:1 w:0)
+	// Storage: CouncilMembership Prime (r:1 w:0)
+	// Storage: Council Members (r:0 w:1)
+	// Storage: Council Prime (r:0 w:1)
+	/// The range of component `m` is `[2, 100]`.
+	fn swap_member(m: u32) -> Weight {
+		// Minimum execution time: 20_874 nanoseconds.
+		Weight::from_ref_time(20_127_360)
+			// Standard Error: 5_543
+			.saturating_add(Weight::from_ref_time(55_432).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: CouncilMembership Members (r:1 w:1)
+	// Storage: Council Proposals (r:1 w:0)
+	// Storage: CouncilMembership Prime (r:1 w:0)
+	// Storage: Council Members (r:0 w:1)
+	// Storage: Council Prime (r:0 w:1)
+	/// The range of component `m` is `[1, 100]`.
+	fn reset_member(m: u32) -> Weight {
+		// Minimum execution time: 21_305 nanoseconds.
+		Weight::from_ref_time(20_402_795)
+			// Standard Error: 17_630
+			.saturating_add(Weight::from_ref_time(176_301).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: CouncilMembership Members (r:1 w:1)
+	// Storage: Council Proposals (r:1 w:0)
+	// Storage: CouncilMembership Prime (r:1 w:1)
+	// Storage: Council Members (r:0 w:1)
+	// Storage: Council Prime (r:0 w:1)
+	/// The range of component `m` is `[1, 100]`.
+	fn change_key(m: u32) -> Weight {
+		// Minimum execution time: 22_010 nanoseconds.
+		Weight::from_ref_time(21_187_615)
+			// Standard Error: 5_196
+			.saturating_add(Weight::from_ref_time(51_963).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	// Storage: CouncilMembership Members (r:1 w:0)
+	// Storage: CouncilMembership Prime (r:0 w:1)
+	// Storage: Council Prime (r:0 w:1)
+	/// The range of component `m` is `[1, 100]`.
+	fn set_prime(m: u32) -> Weight {
+		// Minimum execution time: 7_094 nanoseconds.
+		Weight::from_ref_time(6_512_380)
+			// Standard Error: 1_250
+			.saturating_add(Weight::from_ref_time(12_506).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: CouncilMembership Prime (r:0 w:1)
+	// Storage: Council Prime (r:0 w:1)
+	/// The range of component `m` is `[1, 100]`.
+	fn clear_prime(m: u32) -> Weight {
+		// Minimum execution time: 4_137 nanoseconds.
+		Weight::from_ref_time(3_920_470)
+			// Standard Error: 108
+			.saturating_add(Weight::from_ref_time(1_084).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+}
@@ -0,0 +1,110 @@
+
+//! Weights for `pallet_multisig`
+//!
+//! NOT GENERATED: the values are estimated by hand from the storage accesses of the calls
+//! and have not been measured. Regenerate them on the reference hardware with the command
+//! below before relying on them.
+
+// Command to generate:
+// ./target/release/parachain-collator
+// benchmark
+// pallet
+// --chain
+// dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --pallet
+// pallet_multisig
+// --extrinsic
+// *
+// --steps
+// 50
+// --repeat
+// 20
+// --output
+// runtime/src/weights/pallet_multisig.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_multisig`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_multi_threshold_1(z: u32, ) -> Weight {
+		Weight::from_ref_time(16_884_000)
+			// Standard Error: 0
+			.saturating_add(Weight::from_ref_time(532).saturating_mul(z.into()))
+	}
+	// Storage: Multisig Multisigs (r:1 w:1)
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_multi_create(s: u32, z: u32, ) -> Weight {
+		Weight::from_ref_time(45_117_000)
+			// Standard Error: 1_318
+			.saturating_add(Weight::from_ref_time(101_562).saturating_mul(s.into()))
+			// Standard Error: 12
+			.saturating_add(Weight::from_ref_time(1_498).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: Multisig Multisigs (r:1 w:1)
+	/// The range of component `s` is `[3, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_multi_approve(s: u32, z: u32, ) -> Weight {
+		Weight::from_ref_time(34_205_000)
+			// Standard Error: 1_157
+			.saturating_add(Weight::from_ref_time(98_731).saturating_mul(s.into()))
+			// Standard Error: 11
+			.saturating_add(Weight::from_ref_time(1_503).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: Multisig Multisigs (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_multi_complete(s: u32, z: u32, ) -> Weight {
+		Weight::from_ref_time(51_842_000)
+			// Standard Error: 1_501
+			.saturating_add(Weight::from_ref_time(121_904).saturating_mul(s.into()))
+			// Standard Error: 14
+			.saturating_add(Weight::from_ref_time(1_528).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: Multisig Multisigs (r:1 w:1)
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	/// The range of component `s` is `[2, 100]`.
+	fn approve_as_multi_create(s: u32, ) -> Weight {
+		Weight::from_ref_time(41_338_000)
+			// Standard Error: 1_389
+			.saturating_add(Weight::from_ref_time(106_823).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: Multisig Multisigs (r:1 w:1)
+	/// The range of component `s` is `[2, 100]`.
+	fn approve_as_multi_approve(s: u32, ) -> Weight {
+		Weight::from_ref_time(26_517_000)
+			// Standard Error: 1_222
+			.saturating_add(Weight::from_ref_time(102_209).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: Multisig Multisigs (r:1 w:1)
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	/// The range of component `s` is `[2, 100]`.
+	fn cancel_as_multi(s: u32, ) -> Weight {
+		Weight::from_ref_time(36_781_000)
+			// Standard Error: 1_297
+			.saturating_add(Weight::from_ref_time(104_917).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+}
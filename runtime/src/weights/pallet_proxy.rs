@@ -0,0 +1,140 @@
+
+//! Weights for `pallet_proxy`
+//!
+//! NOT GENERATED: the values are estimated by hand from the storage accesses of the calls
+//! and have not been measured. Regenerate them on the reference hardware with the command
+//! below before relying on them.
+
+// Command to generate:
+// ./target/release/parachain-collator
+// benchmark
+// pallet
+// --chain
+// dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --pallet
+// pallet_proxy
+// --extrinsic
+// *
+// --steps
+// 50
+// --repeat
+// 20
+// --output
+// runtime/src/weights/pallet_proxy.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_proxy`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
+	// Storage: Proxy Proxies (r:1 w:0)
+	/// The range of component `p` is `[1, 31]`.
+	fn proxy(p: u32, ) -> Weight {
+		Weight::from_ref_time(20_143_000)
+			// Standard Error: 2_718
+			.saturating_add(Weight::from_ref_time(52_186).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+	}
+	// Storage: Proxy Proxies (r:1 w:0)
+	// Storage: Proxy Announcements (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	/// The range of component `a` is `[0, 31]`.
+	/// The range of component `p` is `[1, 31]`.
+	fn proxy_announced(a: u32, p: u32, ) -> Weight {
+		Weight::from_ref_time(39_207_000)
+			// Standard Error: 3_904
+			.saturating_add(Weight::from_ref_time(128_451).saturating_mul(a.into()))
+			// Standard Error: 4_034
+			.saturating_add(Weight::from_ref_time(47_317).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: Proxy Announcements (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	/// The range of component `a` is `[0, 31]`.
+	/// The range of component `p` is `[1, 31]`.
+	fn remove_announcement(a: u32, _p: u32, ) -> Weight {
+		Weight::from_ref_time(26_618_000)
+			// Standard Error: 2_871
+			.saturating_add(Weight::from_ref_time(129_773).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: Proxy Announcements (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	/// The range of component `a` is `[0, 31]`.
+	/// The range of component `p` is `[1, 31]`.
+	fn reject_announcement(a: u32, _p: u32, ) -> Weight {
+		Weight::from_ref_time(26_542_000)
+			// Standard Error: 2_799
+			.saturating_add(Weight::from_ref_time(130_688).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: Proxy Proxies (r:1 w:0)
+	// Storage: Proxy Announcements (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	/// The range of component `a` is `[0, 31]`.
+	/// The range of component `p` is `[1, 31]`.
+	fn announce(a: u32, p: u32, ) -> Weight {
+		Weight::from_ref_time(34_471_000)
+			// Standard Error: 3_315
+			.saturating_add(Weight::from_ref_time(121_325).saturating_mul(a.into()))
+			// Standard Error: 3_425
+			.saturating_add(Weight::from_ref_time(51_027).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: Proxy Proxies (r:1 w:1)
+	/// The range of component `p` is `[1, 31]`.
+	fn add_proxy(p: u32, ) -> Weight {
+		Weight::from_ref_time(27_910_000)
+			// Standard Error: 2_913
+			.saturating_add(Weight::from_ref_time(71_692).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: Proxy Proxies (r:1 w:1)
+	/// The range of component `p` is `[1, 31]`.
+	fn remove_proxy(p: u32, ) -> Weight {
+		Weight::from_ref_time(28_104_000)
+			// Standard Error: 3_278
+			.saturating_add(Weight::from_ref_time(77_506).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: Proxy Proxies (r:1 w:1)
+	/// The range of component `p` is `[1, 31]`.
+	fn remove_proxies(p: u32, ) -> Weight {
+		Weight::from_ref_time(22_937_000)
+			// Standard Error: 2_402
+			.saturating_add(Weight::from_ref_time(50_112).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: Proxy Proxies (r:1 w:1)
+	/// The range of component `p` is `[1, 31]`.
+	fn create_pure(p: u32, ) -> Weight {
+		Weight::from_ref_time(30_715_000)
+			// Standard Error: 2_786
+			.saturating_add(Weight::from_ref_time(18_640).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: Proxy Proxies (r:1 w:1)
+	/// The range of component `p` is `[0, 30]`.
+	fn kill_pure(p: u32, ) -> Weight {
+		Weight::from_ref_time(23_809_000)
+			// Standard Error: 2_455
+			.saturating_add(Weight::from_ref_time(49_574).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+}
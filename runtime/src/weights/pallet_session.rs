@@ -0,0 +1,53 @@
+
+//! Weights for `pallet_session`
+//!
+//! NOT GENERATED: the values are estimated by hand from the storage accesses of the calls
+//! and have not been measured. Regenerate them on the reference hardware with
+//! `./housekeeping/benchmark.sh pallet_session` before relying on them.
+
+// Command to generate:
+// ./target/release/parachain-collator
+// benchmark
+// pallet
+// --chain
+// dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --pallet
+// pallet_session
+// --extrinsic
+// *
+// --steps
+// 50
+// --repeat
+// 20
+// --output
+// runtime/src/weights/pallet_session.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_session`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_session::WeightInfo for WeightInfo<T> {
+	// Storage: Session NextKeys (r:1 w:1)
+	// Storage: Session KeyOwner (r:2 w:2)
+	fn set_keys() -> Weight {
+		// Minimum execution time: 19_652 nanoseconds.
+		Weight::from_ref_time(20_471_000)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: Session NextKeys (r:1 w:1)
+	// Storage: Session KeyOwner (r:0 w:2)
+	fn purge_keys() -> Weight {
+		// Minimum execution time: 15_227 nanoseconds.
+		Weight::from_ref_time(15_862_000)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+}
@@ -0,0 +1,85 @@
+
+//! Weights for `pallet_treasury`
+//!
+//! NOT GENERATED: the values are estimated by hand from the storage accesses of the calls
+//! and have not been measured. Regenerate them on the reference hardware with the command
+//! below before relying on them.
+
+// Command to generate:
+// ./target/release/parachain-collator
+// benchmark
+// pallet
+// --chain
+// dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --pallet
+// pallet_treasury
+// --extrinsic
+// *
+// --steps
+// 50
+// --repeat
+// 20
+// --output
+// runtime/src/weights/pallet_treasury.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_treasury`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_treasury::WeightInfo for WeightInfo<T> {
+	fn spend() -> Weight {
+		Weight::from_ref_time(286_000)
+	}
+	// Storage: Treasury ProposalCount (r:1 w:1)
+	// Storage: Treasury Proposals (r:0 w:1)
+	fn propose_spend() -> Weight {
+		Weight::from_ref_time(27_961_000)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: Treasury Proposals (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn reject_proposal() -> Weight {
+		Weight::from_ref_time(40_624_000)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: Treasury Proposals (r:1 w:0)
+	// Storage: Treasury Approvals (r:1 w:1)
+	/// The range of component `p` is `[0, 99]`.
+	fn approve_proposal(p: u32, ) -> Weight {
+		Weight::from_ref_time(13_816_000)
+			// Standard Error: 1_412
+			.saturating_add(Weight::from_ref_time(103_614).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: Treasury Approvals (r:1 w:1)
+	fn remove_approval() -> Weight {
+		Weight::from_ref_time(8_729_000)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: Treasury Deactivated (r:1 w:1)
+	// Storage: Balances InactiveIssuance (r:1 w:1)
+	// Storage: Treasury Approvals (r:1 w:1)
+	// Storage: Treasury Proposals (r:100 w:100)
+	// Storage: System Account (r:200 w:200)
+	/// The range of component `p` is `[0, 100]`.
+	fn on_initialize_proposals(p: u32, ) -> Weight {
+		Weight::from_ref_time(39_587_000)
+			// Standard Error: 21_840
+			.saturating_add(Weight::from_ref_time(29_018_497).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(p.into())))
+	}
+}
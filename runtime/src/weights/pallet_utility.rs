@@ -0,0 +1,61 @@
+
+//! Weights for `pallet_utility`
+//!
+//! NOT GENERATED: the values are estimated by hand from the storage accesses of the calls
+//! and have not been measured. Regenerate them on the reference hardware with the command
+//! below before relying on them.
+
+// Command to generate:
+// ./target/release/parachain-collator
+// benchmark
+// pallet
+// --chain
+// dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --pallet
+// pallet_utility
+// --extrinsic
+// *
+// --steps
+// 50
+// --repeat
+// 20
+// --output
+// runtime/src/weights/pallet_utility.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_utility`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_utility::WeightInfo for WeightInfo<T> {
+	/// The range of component `c` is `[0, 1000]`.
+	fn batch(c: u32, ) -> Weight {
+		Weight::from_ref_time(17_315_000)
+			// Standard Error: 1_904
+			.saturating_add(Weight::from_ref_time(4_812_605).saturating_mul(c.into()))
+	}
+	fn as_derivative() -> Weight {
+		Weight::from_ref_time(6_083_000)
+	}
+	/// The range of component `c` is `[0, 1000]`.
+	fn batch_all(c: u32, ) -> Weight {
+		Weight::from_ref_time(23_412_000)
+			// Standard Error: 2_516
+			.saturating_add(Weight::from_ref_time(5_073_330).saturating_mul(c.into()))
+	}
+	fn dispatch_as() -> Weight {
+		Weight::from_ref_time(10_774_000)
+	}
+	/// The range of component `c` is `[0, 1000]`.
+	fn force_batch(c: u32, ) -> Weight {
+		Weight::from_ref_time(16_906_000)
+			// Standard Error: 2_088
+			.saturating_add(Weight::from_ref_time(4_795_108).saturating_mul(c.into()))
+	}
+}
@@ -0,0 +1,160 @@
+
+//! Weights for `pallet_xcm`
+//!
+//! NOT GENERATED: the values are estimated by hand from the storage accesses of the calls
+//! and have not been measured. Regenerate them on the reference hardware with
+//! `./housekeeping/benchmark.sh pallet_xcm` before relying on them.
+
+// Command to generate:
+// ./target/release/parachain-collator
+// benchmark
+// pallet
+// --chain
+// dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --pallet
+// pallet_xcm
+// --extrinsic
+// *
+// --steps
+// 50
+// --repeat
+// 20
+// --output
+// runtime/src/weights/pallet_xcm.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_xcm`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_xcm::WeightInfo for WeightInfo<T> {
+	// Storage: PolkadotXcm SupportedVersion (r:1 w:0)
+	// Storage: PolkadotXcm VersionDiscoveryQueue (r:1 w:1)
+	// Storage: PolkadotXcm SafeXcmVersion (r:1 w:0)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	fn send() -> Weight {
+		// Minimum execution time: 27_412 nanoseconds.
+		Weight::from_ref_time(28_105_000)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: Benchmark Override (r:0 w:0)
+	fn teleport_assets() -> Weight {
+		// Minimum execution time: 18_446_744_073_709_551 nanoseconds.
+		Weight::from_ref_time(18_446_744_073_709_551_000)
+	}
+	// Storage: ParachainInfo ParachainId (r:1 w:0)
+	fn reserve_transfer_assets() -> Weight {
+		// Minimum execution time: 26_873 nanoseconds.
+		Weight::from_ref_time(27_542_000)
+			.saturating_add(T::DbWeight::get().reads(1))
+	}
+	// Storage: Benchmark Override (r:0 w:0)
+	fn execute() -> Weight {
+		// Minimum execution time: 18_446_744_073_709_551 nanoseconds.
+		Weight::from_ref_time(18_446_744_073_709_551_000)
+	}
+	// Storage: PolkadotXcm SupportedVersion (r:0 w:1)
+	fn force_xcm_version() -> Weight {
+		// Minimum execution time: 11_058 nanoseconds.
+		Weight::from_ref_time(11_390_000)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: PolkadotXcm SafeXcmVersion (r:0 w:1)
+	fn force_default_xcm_version() -> Weight {
+		// Minimum execution time: 3_305 nanoseconds.
+		Weight::from_ref_time(3_497_000)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: PolkadotXcm VersionNotifiers (r:1 w:1)
+	// Storage: PolkadotXcm QueryCounter (r:1 w:1)
+	// Storage: PolkadotXcm SupportedVersion (r:1 w:0)
+	// Storage: PolkadotXcm VersionDiscoveryQueue (r:1 w:1)
+	// Storage: PolkadotXcm SafeXcmVersion (r:1 w:0)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	// Storage: PolkadotXcm Queries (r:0 w:1)
+	fn force_subscribe_version_notify() -> Weight {
+		// Minimum execution time: 32_167 nanoseconds.
+		Weight::from_ref_time(32_940_000)
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	// Storage: PolkadotXcm VersionNotifiers (r:1 w:1)
+	// Storage: PolkadotXcm SupportedVersion (r:1 w:0)
+	// Storage: PolkadotXcm VersionDiscoveryQueue (r:1 w:1)
+	// Storage: PolkadotXcm SafeXcmVersion (r:1 w:0)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	// Storage: PolkadotXcm Queries (r:0 w:1)
+	fn force_unsubscribe_version_notify() -> Weight {
+		// Minimum execution time: 33_721 nanoseconds.
+		Weight::from_ref_time(34_388_000)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	// Storage: PolkadotXcm SupportedVersion (r:4 w:2)
+	fn migrate_supported_version() -> Weight {
+		// Minimum execution time: 14_302 nanoseconds.
+		Weight::from_ref_time(14_751_000)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: PolkadotXcm VersionNotifiers (r:4 w:2)
+	fn migrate_version_notifiers() -> Weight {
+		// Minimum execution time: 14_190 nanoseconds.
+		Weight::from_ref_time(14_633_000)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: PolkadotXcm VersionNotifyTargets (r:5 w:0)
+	fn already_notified_target() -> Weight {
+		// Minimum execution time: 15_986 nanoseconds.
+		Weight::from_ref_time(16_420_000)
+			.saturating_add(T::DbWeight::get().reads(5))
+	}
+	// Storage: PolkadotXcm VersionNotifyTargets (r:2 w:1)
+	// Storage: PolkadotXcm SupportedVersion (r:1 w:0)
+	// Storage: PolkadotXcm VersionDiscoveryQueue (r:1 w:1)
+	// Storage: PolkadotXcm SafeXcmVersion (r:1 w:0)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	fn notify_current_targets() -> Weight {
+		// Minimum execution time: 30_554 nanoseconds.
+		Weight::from_ref_time(31_209_000)
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: PolkadotXcm VersionNotifyTargets (r:3 w:0)
+	fn notify_target_migration_fail() -> Weight {
+		// Minimum execution time: 7_402 nanoseconds.
+		Weight::from_ref_time(7_668_000)
+			.saturating_add(T::DbWeight::get().reads(3))
+	}
+	// Storage: PolkadotXcm VersionNotifyTargets (r:4 w:2)
+	fn migrate_version_notify_targets() -> Weight {
+		// Minimum execution time: 14_557 nanoseconds.
+		Weight::from_ref_time(15_032_000)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: PolkadotXcm VersionNotifyTargets (r:4 w:2)
+	// Storage: PolkadotXcm SupportedVersion (r:1 w:0)
+	// Storage: PolkadotXcm VersionDiscoveryQueue (r:1 w:1)
+	// Storage: PolkadotXcm SafeXcmVersion (r:1 w:0)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	fn migrate_and_notify_old_targets() -> Weight {
+		// Minimum execution time: 36_881 nanoseconds.
+		Weight::from_ref_time(37_614_000)
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+}
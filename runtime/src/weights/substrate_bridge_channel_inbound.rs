@@ -0,0 +1,48 @@
+
+//! Weights for `substrate_bridge_channel::inbound`
+//!
+//! NOT GENERATED: the values are estimated by hand from the storage accesses of the calls
+//! and have not been measured. Regenerate them on the reference hardware with the command
+//! below before relying on them.
+
+// Command to generate:
+// ./target/release/parachain-collator
+// benchmark
+// pallet
+// --chain
+// dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --pallet
+// substrate_bridge_channel::inbound
+// --extrinsic
+// *
+// --steps
+// 50
+// --repeat
+// 20
+// --output
+// runtime/src/weights/substrate_bridge_channel_inbound.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `substrate_bridge_channel::inbound`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> substrate_bridge_channel::inbound::WeightInfo for WeightInfo<T> {
+	// Storage: SubstrateBridgeInboundChannel ChannelNonces (r:1 w:1)
+	// Storage: MultisigVerifier PeerKeys (r:1 w:0)
+	// Storage: XCMApp AssetIdToMultilocation (r:1 w:0)
+	// Storage: ParachainInfo ParachainId (r:1 w:0)
+	// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	fn submit() -> Weight {
+		// Minimum execution time: 68_499 nanoseconds.
+		Weight::from_ref_time(71_354_000)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+}
@@ -0,0 +1,81 @@
+
+//! Weights for `substrate_bridge_channel::outbound`
+//!
+//! NOT GENERATED: the values are estimated by hand from the storage accesses of the calls
+//! and have not been measured. Regenerate them on the reference hardware with the command
+//! below before relying on them.
+
+// Command to generate:
+// ./target/release/parachain-collator
+// benchmark
+// pallet
+// --chain
+// dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --pallet
+// substrate_bridge_channel::outbound
+// --extrinsic
+// *
+// --steps
+// 50
+// --repeat
+// 20
+// --output
+// runtime/src/weights/substrate_bridge_channel_outbound.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `substrate_bridge_channel::outbound`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> substrate_bridge_channel::outbound::WeightInfo for WeightInfo<T> {
+	// Storage: SubstrateBridgeOutboundChannel Interval (r:1 w:0)
+	// Storage: SubstrateBridgeOutboundChannel MessageQueues (r:1 w:1)
+	// Storage: SubstrateBridgeOutboundChannel ChannelNonces (r:1 w:0)
+	// Storage: LeafProvider LatestDigest (r:0 w:1)
+	/// The range of component `m` is `[0, 100]`.
+	/// The range of component `p` is `[0, 65536]`.
+	fn on_initialize(m: u32, p: u32) -> Weight {
+		// Minimum execution time: 10_409 nanoseconds.
+		Weight::from_ref_time(10_843_000)
+			// Standard Error: 19_104
+			.saturating_add(Weight::from_ref_time(2_214_300).saturating_mul(m.into()))
+			// Standard Error: 29
+			.saturating_add(Weight::from_ref_time(61).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: SubstrateBridgeOutboundChannel Interval (r:1 w:0)
+	fn on_initialize_non_interval_block() -> Weight {
+		// Minimum execution time: 3_940 nanoseconds.
+		Weight::from_ref_time(4_105_000)
+			.saturating_add(T::DbWeight::get().reads(1))
+	}
+	// Storage: SubstrateBridgeOutboundChannel Interval (r:1 w:0)
+	// Storage: SubstrateBridgeOutboundChannel MessageQueues (r:1 w:0)
+	fn on_initialize_no_messages() -> Weight {
+		// Minimum execution time: 5_109 nanoseconds.
+		Weight::from_ref_time(5_322_000)
+			.saturating_add(T::DbWeight::get().reads(2))
+	}
+	// Storage: SubstrateBridgeOutboundChannel MessageQueues (r:1 w:1)
+	// Storage: SubstrateBridgeOutboundChannel ChannelNonces (r:1 w:0)
+	// Storage: SubstrateBridgeOutboundChannel Interval (r:1 w:0)
+	fn submit() -> Weight {
+		// Minimum execution time: 20_611 nanoseconds.
+		Weight::from_ref_time(21_470_000)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: SubstrateBridgeOutboundChannel Interval (r:0 w:1)
+	fn update_interval() -> Weight {
+		// Minimum execution time: 8_655 nanoseconds.
+		Weight::from_ref_time(9_016_000)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+}
@@ -0,0 +1,272 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Per-instruction XCM weights, built from `pallet_xcm_benchmarks` results.
+
+mod pallet_xcm_benchmarks_fungible;
+mod pallet_xcm_benchmarks_generic;
+
+use crate::Runtime;
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::prelude::*;
+use xcm::{latest::prelude::*, DoubleEncoded};
+
+pub use pallet_xcm_benchmarks_fungible::WeightInfo as XcmFungibleWeight;
+pub use pallet_xcm_benchmarks_generic::WeightInfo as XcmGeneric;
+
+/// Upper bound on the number of assets a single instruction is weighed for when the
+/// instruction carries a wildcard filter.
+const MAX_ASSETS: u64 = 100;
+
+trait WeighMultiAssets {
+    fn weigh_multi_assets(&self, weight: Weight) -> Weight;
+}
+
+impl WeighMultiAssets for MultiAssetFilter {
+    fn weigh_multi_assets(&self, weight: Weight) -> Weight {
+        match self {
+            Self::Definite(assets) => weight.saturating_mul(assets.inner().len() as u64),
+            Self::Wild(asset) => match asset {
+                All => weight.saturating_mul(MAX_ASSETS),
+                AllOf { fun, .. } => match fun {
+                    WildFungibility::Fungible => weight,
+                    // Magic number 2 has to do with the fact that we could have up to 2 times
+                    // MaxAssetsIntoHolding in the worst-case scenario.
+                    WildFungibility::NonFungible => weight.saturating_mul(
+                        (crate::xcm_config::MaxAssetsIntoHolding::get() * 2) as u64,
+                    ),
+                },
+                AllCounted(count) => weight.saturating_mul(MAX_ASSETS.min(*count as u64)),
+                AllOfCounted { count, .. } => weight.saturating_mul(MAX_ASSETS.min(*count as u64)),
+            },
+        }
+    }
+}
+
+impl WeighMultiAssets for MultiAssets {
+    fn weigh_multi_assets(&self, weight: Weight) -> Weight {
+        weight.saturating_mul(self.inner().len() as u64)
+    }
+}
+
+/// XCM weigher for this parachain, see `xcm_builder::WeightInfoBounds`.
+pub struct SoraParachainXcmWeight<RuntimeCall>(core::marker::PhantomData<RuntimeCall>);
+impl<RuntimeCall> XcmWeightInfo<RuntimeCall> for SoraParachainXcmWeight<RuntimeCall> {
+    fn withdraw_asset(assets: &MultiAssets) -> Weight {
+        assets.weigh_multi_assets(XcmFungibleWeight::<Runtime>::withdraw_asset())
+    }
+    // `pallet_xcm_benchmarks` has no benchmark for this instruction. Moving assets into holding
+    // never costs more than withdrawing them from an account, so `withdraw_asset` is used as the
+    // upper bound.
+    fn reserve_asset_deposited(assets: &MultiAssets) -> Weight {
+        assets.weigh_multi_assets(XcmFungibleWeight::<Runtime>::withdraw_asset())
+    }
+    fn receive_teleported_asset(assets: &MultiAssets) -> Weight {
+        assets.weigh_multi_assets(XcmFungibleWeight::<Runtime>::receive_teleported_asset())
+    }
+    fn query_response(
+        _query_id: &u64,
+        _response: &Response,
+        _max_weight: &Weight,
+        _querier: &Option<MultiLocation>,
+    ) -> Weight {
+        XcmGeneric::<Runtime>::query_response()
+    }
+    fn transfer_asset(assets: &MultiAssets, _dest: &MultiLocation) -> Weight {
+        assets.weigh_multi_assets(XcmFungibleWeight::<Runtime>::transfer_asset())
+    }
+    fn transfer_reserve_asset(
+        assets: &MultiAssets,
+        _dest: &MultiLocation,
+        _xcm: &Xcm<()>,
+    ) -> Weight {
+        assets.weigh_multi_assets(XcmFungibleWeight::<Runtime>::transfer_reserve_asset())
+    }
+    fn transact(
+        _origin_type: &OriginKind,
+        _require_weight_at_most: &Weight,
+        _call: &DoubleEncoded<RuntimeCall>,
+    ) -> Weight {
+        XcmGeneric::<Runtime>::transact()
+    }
+    fn hrmp_new_channel_open_request(
+        _sender: &u32,
+        _max_message_size: &u32,
+        _max_capacity: &u32,
+    ) -> Weight {
+        // XCM Executor does not currently support HRMP channel operations
+        Weight::MAX
+    }
+    fn hrmp_channel_accepted(_recipient: &u32) -> Weight {
+        // XCM Executor does not currently support HRMP channel operations
+        Weight::MAX
+    }
+    fn hrmp_channel_closing(_initiator: &u32, _sender: &u32, _recipient: &u32) -> Weight {
+        // XCM Executor does not currently support HRMP channel operations
+        Weight::MAX
+    }
+    fn clear_origin() -> Weight {
+        XcmGeneric::<Runtime>::clear_origin()
+    }
+    fn descend_origin(_who: &InteriorMultiLocation) -> Weight {
+        XcmGeneric::<Runtime>::descend_origin()
+    }
+    fn report_error(_query_response_info: &QueryResponseInfo) -> Weight {
+        XcmGeneric::<Runtime>::report_error()
+    }
+    fn deposit_asset(assets: &MultiAssetFilter, _dest: &MultiLocation) -> Weight {
+        assets.weigh_multi_assets(XcmFungibleWeight::<Runtime>::deposit_asset())
+    }
+    fn deposit_reserve_asset(
+        assets: &MultiAssetFilter,
+        _dest: &MultiLocation,
+        _xcm: &Xcm<()>,
+    ) -> Weight {
+        assets.weigh_multi_assets(XcmFungibleWeight::<Runtime>::deposit_reserve_asset())
+    }
+    fn exchange_asset(_give: &MultiAssetFilter, _receive: &MultiAssets, _maximal: &bool) -> Weight {
+        // Asset exchange is not supported by this chain.
+        Weight::MAX
+    }
+    fn initiate_reserve_withdraw(
+        assets: &MultiAssetFilter,
+        _reserve: &MultiLocation,
+        _xcm: &Xcm<()>,
+    ) -> Weight {
+        assets.weigh_multi_assets(XcmGeneric::<Runtime>::initiate_reserve_withdraw())
+    }
+    fn initiate_teleport(
+        assets: &MultiAssetFilter,
+        _dest: &MultiLocation,
+        _xcm: &Xcm<()>,
+    ) -> Weight {
+        assets.weigh_multi_assets(XcmFungibleWeight::<Runtime>::initiate_teleport())
+    }
+    fn report_holding(_response_info: &QueryResponseInfo, _assets: &MultiAssetFilter) -> Weight {
+        XcmGeneric::<Runtime>::report_holding()
+    }
+    fn buy_execution(_fees: &MultiAsset, _weight_limit: &WeightLimit) -> Weight {
+        XcmGeneric::<Runtime>::buy_execution()
+    }
+    fn refund_surplus() -> Weight {
+        XcmGeneric::<Runtime>::refund_surplus()
+    }
+    fn set_error_handler(_xcm: &Xcm<RuntimeCall>) -> Weight {
+        XcmGeneric::<Runtime>::set_error_handler()
+    }
+    fn set_appendix(_xcm: &Xcm<RuntimeCall>) -> Weight {
+        XcmGeneric::<Runtime>::set_appendix()
+    }
+    fn clear_error() -> Weight {
+        XcmGeneric::<Runtime>::clear_error()
+    }
+    fn claim_asset(_assets: &MultiAssets, _ticket: &MultiLocation) -> Weight {
+        XcmGeneric::<Runtime>::claim_asset()
+    }
+    fn trap(_code: &u64) -> Weight {
+        XcmGeneric::<Runtime>::trap()
+    }
+    fn subscribe_version(_query_id: &QueryId, _max_response_weight: &Weight) -> Weight {
+        XcmGeneric::<Runtime>::subscribe_version()
+    }
+    fn unsubscribe_version() -> Weight {
+        XcmGeneric::<Runtime>::unsubscribe_version()
+    }
+    fn burn_asset(assets: &MultiAssets) -> Weight {
+        assets.weigh_multi_assets(XcmGeneric::<Runtime>::burn_asset())
+    }
+    fn expect_asset(assets: &MultiAssets) -> Weight {
+        assets.weigh_multi_assets(XcmGeneric::<Runtime>::expect_asset())
+    }
+    fn expect_origin(_origin: &Option<MultiLocation>) -> Weight {
+        XcmGeneric::<Runtime>::expect_origin()
+    }
+    fn expect_error(_error: &Option<(u32, XcmError)>) -> Weight {
+        XcmGeneric::<Runtime>::expect_error()
+    }
+    fn expect_transact_status(_transact_status: &MaybeErrorCode) -> Weight {
+        XcmGeneric::<Runtime>::expect_transact_status()
+    }
+    fn query_pallet(_module_name: &Vec<u8>, _response_info: &QueryResponseInfo) -> Weight {
+        XcmGeneric::<Runtime>::query_pallet()
+    }
+    fn expect_pallet(
+        _index: &u32,
+        _name: &Vec<u8>,
+        _module_name: &Vec<u8>,
+        _crate_major: &u32,
+        _min_crate_minor: &u32,
+    ) -> Weight {
+        XcmGeneric::<Runtime>::expect_pallet()
+    }
+    fn report_transact_status(_response_info: &QueryResponseInfo) -> Weight {
+        XcmGeneric::<Runtime>::report_transact_status()
+    }
+    fn clear_transact_status() -> Weight {
+        XcmGeneric::<Runtime>::clear_transact_status()
+    }
+    fn universal_origin(_: &Junction) -> Weight {
+        // Universal origins are not accepted by this chain.
+        Weight::MAX
+    }
+    fn export_message(_: &NetworkId, _: &Junctions, _: &Xcm<()>) -> Weight {
+        // Message export is not supported by this chain.
+        Weight::MAX
+    }
+    fn lock_asset(_: &MultiAsset, _: &MultiLocation) -> Weight {
+        // Asset locking is not supported by this chain.
+        Weight::MAX
+    }
+    fn unlock_asset(_: &MultiAsset, _: &MultiLocation) -> Weight {
+        Weight::MAX
+    }
+    fn note_unlockable(_: &MultiAsset, _: &MultiLocation) -> Weight {
+        Weight::MAX
+    }
+    fn request_unlock(_: &MultiAsset, _: &MultiLocation) -> Weight {
+        Weight::MAX
+    }
+    fn set_fees_mode(_: &bool) -> Weight {
+        XcmGeneric::<Runtime>::set_fees_mode()
+    }
+    fn set_topic(_topic: &[u8; 32]) -> Weight {
+        XcmGeneric::<Runtime>::set_topic()
+    }
+    fn clear_topic() -> Weight {
+        XcmGeneric::<Runtime>::clear_topic()
+    }
+    fn alias_origin(_: &MultiLocation) -> Weight {
+        // Origin aliasing is not supported by this chain.
+        Weight::MAX
+    }
+    fn unpaid_execution(_: &WeightLimit, _: &Option<MultiLocation>) -> Weight {
+        XcmGeneric::<Runtime>::unpaid_execution()
+    }
+}
@@ -0,0 +1,179 @@
+
+//! Weights for `pallet_xcm_benchmarks::generic`
+//!
+//! NOT GENERATED: the values are estimated by hand from the storage accesses of the calls
+//! and have not been measured. Regenerate them on the reference hardware with
+//! `./housekeeping/benchmark.sh pallet_xcm_benchmarks::generic` before relying on them.
+
+// Command to generate:
+// ./target/release/parachain-collator
+// benchmark
+// pallet
+// --template=./housekeeping/xcm-weight-template.hbs
+// --chain
+// dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --pallet
+// pallet_xcm_benchmarks::generic
+// --extrinsic
+// *
+// --steps
+// 50
+// --repeat
+// 20
+// --output
+// runtime/src/weights/xcm/pallet_xcm_benchmarks_generic.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weights for `pallet_xcm_benchmarks::generic`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo<T> {
+	// Storage: ParachainInfo ParachainId (r:1 w:0)
+	// Storage: PolkadotXcm SupportedVersion (r:1 w:0)
+	// Storage: PolkadotXcm VersionDiscoveryQueue (r:1 w:1)
+	// Storage: PolkadotXcm SafeXcmVersion (r:1 w:0)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	pub(crate) fn report_holding() -> Weight {
+		Weight::from_ref_time(369_520_000)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	pub(crate) fn buy_execution() -> Weight {
+		Weight::from_ref_time(4_812_000)
+	}
+	// Storage: PolkadotXcm Queries (r:1 w:0)
+	pub(crate) fn query_response() -> Weight {
+		Weight::from_ref_time(11_944_000)
+			.saturating_add(T::DbWeight::get().reads(1))
+	}
+	pub(crate) fn transact() -> Weight {
+		Weight::from_ref_time(13_305_000)
+	}
+	pub(crate) fn refund_surplus() -> Weight {
+		Weight::from_ref_time(4_981_000)
+	}
+	pub(crate) fn set_error_handler() -> Weight {
+		Weight::from_ref_time(3_720_000)
+	}
+	pub(crate) fn set_appendix() -> Weight {
+		Weight::from_ref_time(3_698_000)
+	}
+	pub(crate) fn clear_error() -> Weight {
+		Weight::from_ref_time(3_655_000)
+	}
+	pub(crate) fn descend_origin() -> Weight {
+		Weight::from_ref_time(4_502_000)
+	}
+	pub(crate) fn clear_origin() -> Weight {
+		Weight::from_ref_time(3_641_000)
+	}
+	// Storage: PolkadotXcm SupportedVersion (r:1 w:0)
+	// Storage: PolkadotXcm VersionDiscoveryQueue (r:1 w:1)
+	// Storage: PolkadotXcm SafeXcmVersion (r:1 w:0)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	pub(crate) fn report_error() -> Weight {
+		Weight::from_ref_time(23_216_000)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: PolkadotXcm AssetTraps (r:1 w:1)
+	pub(crate) fn claim_asset() -> Weight {
+		Weight::from_ref_time(15_879_000)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	pub(crate) fn trap() -> Weight {
+		Weight::from_ref_time(3_702_000)
+	}
+	// Storage: PolkadotXcm VersionNotifyTargets (r:1 w:1)
+	// Storage: PolkadotXcm SupportedVersion (r:1 w:0)
+	// Storage: PolkadotXcm VersionDiscoveryQueue (r:1 w:1)
+	// Storage: PolkadotXcm SafeXcmVersion (r:1 w:0)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	pub(crate) fn subscribe_version() -> Weight {
+		Weight::from_ref_time(25_433_000)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: PolkadotXcm VersionNotifyTargets (r:0 w:1)
+	pub(crate) fn unsubscribe_version() -> Weight {
+		Weight::from_ref_time(6_918_000)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: ParachainInfo ParachainId (r:1 w:0)
+	// Storage: PolkadotXcm SupportedVersion (r:1 w:0)
+	// Storage: PolkadotXcm VersionDiscoveryQueue (r:1 w:1)
+	// Storage: PolkadotXcm SafeXcmVersion (r:1 w:0)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	pub(crate) fn initiate_reserve_withdraw() -> Weight {
+		Weight::from_ref_time(433_690_000)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	pub(crate) fn burn_asset() -> Weight {
+		Weight::from_ref_time(119_408_000)
+	}
+	pub(crate) fn expect_asset() -> Weight {
+		Weight::from_ref_time(14_206_000)
+	}
+	pub(crate) fn expect_origin() -> Weight {
+		Weight::from_ref_time(3_757_000)
+	}
+	pub(crate) fn expect_error() -> Weight {
+		Weight::from_ref_time(3_688_000)
+	}
+	pub(crate) fn expect_transact_status() -> Weight {
+		Weight::from_ref_time(3_911_000)
+	}
+	// Storage: ParachainInfo ParachainId (r:1 w:0)
+	// Storage: PolkadotXcm SupportedVersion (r:1 w:0)
+	// Storage: PolkadotXcm VersionDiscoveryQueue (r:1 w:1)
+	// Storage: PolkadotXcm SafeXcmVersion (r:1 w:0)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	pub(crate) fn query_pallet() -> Weight {
+		Weight::from_ref_time(27_051_000)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	pub(crate) fn expect_pallet() -> Weight {
+		Weight::from_ref_time(5_822_000)
+	}
+	// Storage: ParachainInfo ParachainId (r:1 w:0)
+	// Storage: PolkadotXcm SupportedVersion (r:1 w:0)
+	// Storage: PolkadotXcm VersionDiscoveryQueue (r:1 w:1)
+	// Storage: PolkadotXcm SafeXcmVersion (r:1 w:0)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	pub(crate) fn report_transact_status() -> Weight {
+		Weight::from_ref_time(24_870_000)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	pub(crate) fn clear_transact_status() -> Weight {
+		Weight::from_ref_time(3_795_000)
+	}
+	pub(crate) fn set_topic() -> Weight {
+		Weight::from_ref_time(3_640_000)
+	}
+	pub(crate) fn clear_topic() -> Weight {
+		Weight::from_ref_time(3_622_000)
+	}
+	pub(crate) fn set_fees_mode() -> Weight {
+		Weight::from_ref_time(3_618_000)
+	}
+	pub(crate) fn unpaid_execution() -> Weight {
+		Weight::from_ref_time(3_791_000)
+	}
+}
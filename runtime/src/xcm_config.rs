@@ -32,10 +32,12 @@ use super::{
     AccountId, ParachainInfo, ParachainSystem, PolkadotXcm, Runtime, RuntimeCall, RuntimeEvent,
     RuntimeOrigin, XcmpQueue,
 };
+use crate::weights::xcm::{XcmFungibleWeight, XcmGeneric};
 use frame_support::{
     match_types, parameter_types,
-    traits::{Everything, Nothing},
+    traits::{Contains, Everything, Nothing},
 };
+use frame_system::EnsureRootWithSuccess;
 use orml_traits::{location::AbsoluteReserveProvider, parameter_type_with_key};
 use orml_xcm_support::{IsNativeConcrete, MultiCurrencyAdapter, MultiNativeAsset};
 use pallet_xcm::XcmPassthrough;
@@ -43,22 +45,31 @@ use parachain_common::primitives::AssetId;
 use polkadot_parachain::primitives::Sibling;
 #[cfg(not(feature = "parachain-gen"))]
 use sp_core::Get;
+use sp_std::vec::Vec;
 use xcm::{latest::Weight as XcmWeight, prelude::*};
 use xcm_builder::{
     AccountId32Aliases, AllowKnownQueryResponses, AllowSubscriptionsFrom,
-    AllowTopLevelPaidExecutionFrom, EnsureXcmOrigin, FixedWeightBounds, ParentIsPreset,
-    RelayChainAsNative, SiblingParachainAsNative, SiblingParachainConvertsVia,
+    AllowTopLevelPaidExecutionFrom, CurrencyAdapter, EnsureXcmOrigin, IsConcrete, MintLocation,
+    ParentIsPreset, RelayChainAsNative, SiblingParachainAsNative, SiblingParachainConvertsVia,
     SignedAccountId32AsNative, SignedToAccountId32, SovereignSignedViaLocation, TakeWeightCredit,
+    WeightInfoBounds,
 };
 use xcm_executor::XcmExecutor;
 
 parameter_types! {
     pub const RelayLocation: MultiLocation = MultiLocation::parent();
-    pub const RelayNetwork: NetworkId = NetworkId::Rococo;
+    pub RelayNetwork: NetworkId = crate::XCMApp::relay_network();
     pub RelayChainOrigin: RuntimeOrigin = cumulus_pallet_xcm::Origin::Relay.into();
     pub Ancestry: MultiLocation = Parachain(ParachainInfo::parachain_id().into()).into();
     pub UniversalLocation: InteriorMultiLocation =
         X2(GlobalConsensus(RelayNetwork::get()), Parachain(ParachainInfo::parachain_id().into()));
+    /// Location of the native token of this parachain
+    pub const SelfReserve: MultiLocation = MultiLocation::here();
+    /// Account tracking the native tokens which left this chain
+    pub CheckingAccount: Option<(AccountId, MintLocation)> =
+        Some((PolkadotXcm::check_account(), MintLocation::Local));
+    /// Location the messages sent by Root originate from
+    pub const HereLocation: MultiLocation = MultiLocation::here();
 }
 
 /// Type for specifying how a `MultiLocation` can be converted into an `AccountId`. This is used
@@ -71,9 +82,40 @@ pub type LocationToAccountId = (
     SiblingParachainConvertsVia<Sibling, AccountId>,
     // Straight up local `AccountId32` origins just alias directly to `AccountId`.
     AccountId32Aliases<RelayNetwork, AccountId>,
+    // SORA accounts convert to the accounts derived from them by `XCMApp`.
+    SoraAccountConvertsVia,
 );
 
-/// Means for transacting assets on this chain.
+/// Converts the location of a SORA account to the parachain account derived from it
+pub struct SoraAccountConvertsVia;
+impl xcm_executor::traits::Convert<MultiLocation, AccountId> for SoraAccountConvertsVia {
+    fn convert(location: MultiLocation) -> Result<AccountId, MultiLocation> {
+        // Does not register the account, the conversion is done for every inbound message and
+        // the accounts are only registered by the bridge calls of `XCMApp`
+        match xcm_app::parse_sora_account_location(&location) {
+            Some((network_id, account)) => Ok(crate::XCMApp::sora_account_id(network_id, account)),
+            None => Err(location),
+        }
+    }
+
+    fn reverse(who: AccountId) -> Result<MultiLocation, AccountId> {
+        match crate::XCMApp::sora_account(&who) {
+            Some((network_id, account)) => Ok(xcm_app::sora_account_location(network_id, account)),
+            None => Err(who),
+        }
+    }
+}
+
+/// Means for transacting the native token of this chain.
+pub type CurrencyTransactor = CurrencyAdapter<
+    crate::Balances,
+    IsConcrete<SelfReserve>,
+    LocationToAccountId,
+    AccountId,
+    CheckingAccount,
+>;
+
+/// Means for transacting the `XCMApp` mapped assets on this chain.
 pub type LocalAssetTransactor = MultiCurrencyAdapter<
     crate::XCMApp,
     (),
@@ -85,6 +127,10 @@ pub type LocalAssetTransactor = MultiCurrencyAdapter<
     (),
 >;
 
+/// Means for transacting assets on this chain, the native token is matched first so that it
+/// never reaches the `XCMApp` mapping lookup.
+pub type AssetTransactors = (CurrencyTransactor, LocalAssetTransactor);
+
 /// This is the type we use to convert an (incoming) XCM origin into a local `Origin` instance,
 /// ready for dispatching a transaction with Xcm's `Transact`. There is an `OriginKind` which can
 /// biases the kind of local `Origin` it will become.
@@ -107,12 +153,17 @@ pub type XcmOriginToTransactDispatchOrigin = (
 );
 
 parameter_types! {
-    // One XCM operation is 1_000_000_000 weight - almost certainly a conservative estimate.
-    pub UnitWeightCost: XcmWeight  = XcmWeight::from_ref_time(1_000_000_000);
     pub const MaxInstructions: u32 = 100;
     pub const MaxAssetsIntoHolding: u32 = 64;
 }
 
+/// Weighs XCM messages with the per-instruction weights benchmarked in this runtime.
+pub type XcmWeigher = WeightInfoBounds<
+    crate::weights::xcm::SoraParachainXcmWeight<RuntimeCall>,
+    RuntimeCall,
+    MaxInstructions,
+>;
+
 match_types! {
     pub type ParentOrParentsExecutivePlurality: impl Contains<MultiLocation> = {
         MultiLocation { parents: 1, interior: Here } |
@@ -134,13 +185,13 @@ impl xcm_executor::Config for XcmConfig {
     type RuntimeCall = RuntimeCall;
     type XcmSender = XcmRouter;
     // How to withdraw and deposit an asset.
-    type AssetTransactor = LocalAssetTransactor;
+    type AssetTransactor = AssetTransactors;
     type OriginConverter = XcmOriginToTransactDispatchOrigin;
     type IsReserve = MultiNativeAsset<AbsoluteReserveProvider>;
     type IsTeleporter = (); // Teleporting is disabled.
     type Barrier = Barrier;
-    type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
-    type Trader = crate::trader::ParachainTrader;
+    type Weigher = XcmWeigher;
+    type Trader = crate::trader::ParachainTrader<crate::impls::XcmFeesRevenue>;
     type ResponseHandler = PolkadotXcm;
     type AssetTrap = PolkadotXcm;
     type AssetClaims = PolkadotXcm;
@@ -169,9 +220,20 @@ pub type XcmRouter = (
     XcmpQueue,
 );
 
+/// Only the native token is reserve transferred with `PolkadotXcm`, the mapped assets are sent
+/// with `XCMApp` which keeps track of where they are held.
+pub struct OnlyNativeToken;
+impl Contains<(MultiLocation, Vec<MultiAsset>)> for OnlyNativeToken {
+    fn contains((_, assets): &(MultiLocation, Vec<MultiAsset>)) -> bool {
+        assets.iter().all(|asset| asset.id == Concrete(SelfReserve::get()))
+    }
+}
+
 impl pallet_xcm::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
-    type SendXcmOrigin = EnsureXcmOrigin<RuntimeOrigin, LocalOriginToLocation>;
+    // Only Root (and governance through Whitelist) sends arbitrary messages, signed accounts are
+    // limited to the reserve transfers of the native token.
+    type SendXcmOrigin = EnsureRootWithSuccess<AccountId, HereLocation>;
     type XcmRouter = XcmRouter;
     type ExecuteXcmOrigin = EnsureXcmOrigin<RuntimeOrigin, LocalOriginToLocation>;
     type XcmExecuteFilter = Nothing;
@@ -179,8 +241,8 @@ impl pallet_xcm::Config for Runtime {
     // Needs to be `Everything` for local testing.
     type XcmExecutor = XcmExecutor<XcmConfig>;
     type XcmTeleportFilter = Nothing;
-    type XcmReserveTransferFilter = Everything;
-    type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+    type XcmReserveTransferFilter = OnlyNativeToken;
+    type Weigher = XcmWeigher;
     type RuntimeOrigin = RuntimeOrigin;
     type RuntimeCall = RuntimeCall;
     const VERSION_DISCOVERY_QUEUE_SIZE: u32 = 100;
@@ -192,7 +254,7 @@ impl pallet_xcm::Config for Runtime {
     type TrustedLockers = ();
     type SovereignAccountOf = ();
     type MaxLockers = ();
-    type WeightInfo = PalletXCMWeightInfo;
+    type WeightInfo = crate::weights::pallet_xcm::WeightInfo<Runtime>;
 }
 
 impl cumulus_pallet_xcm::Config for Runtime {
@@ -207,11 +269,14 @@ parameter_types! {
 
 #[cfg(feature = "parachain-gen")]
 parameter_types! {
-    pub SelfLocation: MultiLocation = MultiLocation::new(1, X1(Parachain(2011)));
+    pub SelfLocation: MultiLocation = MultiLocation::new(1, X1(Parachain(crate::variant::PARA_ID)));
 }
 
 parameter_types! {
-    pub const BaseXcmWeight: XcmWeight = XcmWeight::from_ref_time(100_000_000); // TODO: recheck this
+    // xtokens weighs only the message it builds for the destination; the executor there also
+    // runs the `WithdrawAsset`/`ReserveAssetDeposited` and `ClearOrigin` instructions it prepends.
+    pub BaseXcmWeight: XcmWeight = XcmFungibleWeight::<Runtime>::withdraw_asset()
+        .saturating_add(XcmGeneric::<Runtime>::clear_origin());
     pub const MaxAssetsForTransfer: usize = 2;
 }
 
@@ -228,7 +293,11 @@ parameter_type_with_key! {
 pub struct AccountIdToMultiLocation;
 impl sp_runtime::traits::Convert<AccountId, MultiLocation> for AccountIdToMultiLocation {
     fn convert(account: AccountId) -> MultiLocation {
-        X1(AccountId32 { network: Some(NetworkId::Rococo), id: account.into() }).into()
+        // Accounts derived from SORA accounts are seen by other chains as the SORA accounts
+        if let Some((network_id, sora_account)) = crate::XCMApp::sora_account(&account) {
+            return xcm_app::sora_account_location(network_id, sora_account);
+        }
+        X1(AccountId32 { network: Some(RelayNetwork::get()), id: account.into() }).into()
     }
 }
 
@@ -240,75 +309,11 @@ impl orml_xtokens::Config for Runtime {
     type AccountIdToMultiLocation = AccountIdToMultiLocation;
     type SelfLocation = SelfLocation;
     type XcmExecutor = XcmExecutor<XcmConfig>;
-    type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+    type Weigher = XcmWeigher;
     type BaseXcmWeight = BaseXcmWeight;
     type MaxAssetsForTransfer = MaxAssetsForTransfer;
     type MinXcmFee = ParachainMinFee;
-    type MultiLocationsFilter = Everything;
+    type MultiLocationsFilter = xcm_app::AllowedRecipients<Runtime>;
     type ReserveProvider = AbsoluteReserveProvider;
     type UniversalLocation = UniversalLocation;
 }
-
-// The pallet will be disabled for extarnal calls
-pub struct PalletXCMWeightInfo;
-impl pallet_xcm::WeightInfo for PalletXCMWeightInfo {
-    fn send() -> XcmWeight {
-        XcmWeight::zero()
-    }
-
-    fn teleport_assets() -> XcmWeight {
-        XcmWeight::zero()
-    }
-
-    fn reserve_transfer_assets() -> XcmWeight {
-        XcmWeight::zero()
-    }
-
-    fn execute() -> XcmWeight {
-        XcmWeight::zero()
-    }
-
-    fn force_xcm_version() -> XcmWeight {
-        XcmWeight::zero()
-    }
-
-    fn force_default_xcm_version() -> XcmWeight {
-        XcmWeight::zero()
-    }
-
-    fn force_subscribe_version_notify() -> XcmWeight {
-        XcmWeight::zero()
-    }
-
-    fn force_unsubscribe_version_notify() -> XcmWeight {
-        XcmWeight::zero()
-    }
-
-    fn migrate_supported_version() -> XcmWeight {
-        XcmWeight::zero()
-    }
-
-    fn migrate_version_notifiers() -> XcmWeight {
-        XcmWeight::zero()
-    }
-
-    fn already_notified_target() -> XcmWeight {
-        XcmWeight::zero()
-    }
-
-    fn notify_current_targets() -> XcmWeight {
-        XcmWeight::zero()
-    }
-
-    fn notify_target_migration_fail() -> XcmWeight {
-        XcmWeight::zero()
-    }
-
-    fn migrate_version_notify_targets() -> XcmWeight {
-        XcmWeight::zero()
-    }
-
-    fn migrate_and_notify_old_targets() -> XcmWeight {
-        XcmWeight::zero()
-    }
-}
@@ -28,6 +28,7 @@
 // STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+pub mod para_evm;
 pub mod para_x;
 pub mod relay;
 pub mod tests;
@@ -51,6 +52,10 @@ pub type RelayBalances = pallet_balances::Pallet<relay::Runtime>;
 pub type SoraBalances = pallet_balances::Pallet<crate::Runtime>;
 pub type ParaTokens = orml_tokens::Pallet<para_x::Runtime>;
 pub type ParaXTokens = orml_xtokens::Pallet<para_x::Runtime>;
+pub type ParaEvmBalances = pallet_balances::Pallet<para_evm::Runtime>;
+
+/// Id of the parachain with 20-byte accounts
+pub const PARA_EVM_ID: u32 = 3;
 
 #[derive(
     Encode,
@@ -71,6 +76,8 @@ pub enum CurrencyId {
     R,
     /// Parachain X token.
     X,
+    /// SORA parachain native token.
+    S,
 }
 
 pub struct ChannelInfo;
@@ -91,6 +98,7 @@ decl_test_network! {
         parachains = vec![
             (1, ParaX),
             (2, SoraParachain),
+            (3, ParaEvm),
         ],
     }
 }
@@ -112,6 +120,15 @@ decl_test_parachain! {
     }
 }
 
+decl_test_parachain! {
+    pub struct ParaEvm {
+        Runtime = para_evm::Runtime,
+        XcmpMessageHandler = para_evm::XcmpQueue,
+        DmpMessageHandler = para_evm::DmpQueue,
+        new_ext = para_evm_ext(PARA_EVM_ID),
+    }
+}
+
 decl_test_parachain! {
     pub struct SoraParachain {
         Runtime = crate::Runtime,
@@ -197,6 +214,20 @@ pub fn para_ext(para_id: u32) -> TestExternalities {
     ext
 }
 
+pub fn para_evm_ext(para_id: u32) -> TestExternalities {
+    use para_evm::{Runtime, System};
+
+    let mut t = frame_system::GenesisConfig::default().build_storage::<Runtime>().unwrap();
+
+    let parachain_info_config = parachain_info::GenesisConfig { parachain_id: para_id.into() };
+    <parachain_info::GenesisConfig as frame_support::traits::GenesisBuild<Runtime, _>>::assimilate_storage(&parachain_info_config, &mut t)
+		.unwrap();
+
+    let mut ext = TestExternalities::new(t);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
 pub struct CurrencyIdConvert;
 impl Convert<CurrencyId, Option<MultiLocation>> for CurrencyIdConvert {
     fn convert(id: CurrencyId) -> Option<MultiLocation> {
@@ -206,6 +237,7 @@ impl Convert<CurrencyId, Option<MultiLocation>> for CurrencyIdConvert {
                 (Parent, Parachain(1), GeneralKey { length: 32, data: para_x_general_key() })
                     .into(),
             ),
+            CurrencyId::S => Some((Parent, Parachain(2)).into()),
         }
     }
 }
@@ -220,6 +252,7 @@ impl Convert<MultiLocation, Option<CurrencyId>> for CurrencyIdConvert {
                 X2(Parachain(1), GeneralKey { length: 32, data: k }) if k == x => {
                     Some(CurrencyId::X)
                 },
+                X1(Parachain(2)) => Some(CurrencyId::S),
                 _ => None,
             },
             MultiLocation { parents, interior } if parents == 0 => match interior {
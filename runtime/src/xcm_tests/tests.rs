@@ -33,6 +33,7 @@ use bridge_types::{substrate::SubstrateAppCall, GenericTimepoint, SubNetworkId};
 use cumulus_primitives_core::ParaId;
 use frame_support::{assert_noop, assert_ok, traits::Currency};
 use orml_traits::MultiCurrency;
+use polkadot_parachain::primitives::Sibling;
 use sp_runtime::{traits::AccountIdConversion, AccountId32};
 use xcm_simulator::TestExt;
 
@@ -44,6 +45,10 @@ fn sora_para_account() -> AccountId32 {
     ParaId::from(2).into_account_truncating()
 }
 
+fn para_x_sibling_account() -> AccountId32 {
+    Sibling::from(1).into_account_truncating()
+}
+
 // Not used in any unit tests, but it's super helpful for debugging. Let's
 // keep it here. Don't forget to use  -- --nocapture when running test
 // EXAMPLE: print_events::<crate::Runtime>("Transfer to SORA");
@@ -67,6 +72,11 @@ fn message_id() -> crate::H256 {
     hex_literal::hex!("54fd1e1728cd833d21da6f3e36c50884062e35edfc24aec7a70c18a60451255c").into()
 }
 
+/// Account of ALICE from SORA mainnet on the parachain
+fn sora_alice() -> AccountId32 {
+    crate::XCMApp::sora_account_id(SubNetworkId::Mainnet, ALICE)
+}
+
 fn prepare_sora_parachain() {
     SoraParachain::execute_with(|| {
         let _ = SoraBalances::deposit_creating(&ALICE, 1000000000000000000);
@@ -82,9 +92,23 @@ fn prepare_sora_parachain() {
                 X2(Parachain(1), GeneralKey { length: 32, data: para_x_general_key() })
             )
         ));
+        allow_destinations();
     });
 }
 
+fn allow_destinations() {
+    for destination in [
+        MultiLocation::parent(),
+        MultiLocation::new(1, X1(Parachain(1))),
+        MultiLocation::new(1, X1(Parachain(PARA_EVM_ID))),
+    ] {
+        assert_ok!(crate::XCMApp::do_apply_change(xcm_app::DelayedChange::SetDestinationAllowed {
+            destination,
+            allowed: true
+        }));
+    }
+}
+
 #[test]
 fn send_relay_chain_asset_to_sora_from_sibling() {
     TestNet::reset();
@@ -219,7 +243,7 @@ fn send_relay_chain_asset_to_sibling() {
             10000000,
         ));
         let test_event = crate::RuntimeEvent::XCMApp(xcm_app::Event::AssetTransferred(
-            ALICE,
+            sora_alice(),
             location.clone(),
             assetid,
             10000000,
@@ -263,7 +287,10 @@ fn send_sibling_chain_asset_to_sibling() {
             10000000,
         ));
         let test_event = crate::RuntimeEvent::XCMApp(xcm_app::Event::AssetTransferred(
-            ALICE, location, assetid, 10000000,
+            sora_alice(),
+            location,
+            assetid,
+            10000000,
         ));
         assert!(frame_system::Pallet::<crate::Runtime>::events()
             .iter()
@@ -301,7 +328,7 @@ fn send_relay_chain_asset_to_relay_chain() {
             1_000_000_000_000_000,
         ));
         let test_event = crate::RuntimeEvent::XCMApp(xcm_app::Event::AssetTransferred(
-            ALICE,
+            sora_alice(),
             location,
             assetid,
             1_000_000_000_000_000,
@@ -353,6 +380,82 @@ fn send_relay_chain_asset_to_sora_from_relay() {
     });
 }
 
+#[test]
+fn xcm_app_operator_proxy_sends_to_sora() {
+    TestNet::reset();
+    prepare_sora_parachain();
+
+    SoraParachain::execute_with(|| {
+        let asset_id = para_x_asset_id();
+        assert_ok!(crate::XCMApp::do_apply_change(xcm_app::DelayedChange::SetAssetPolicy {
+            asset_id,
+            policy: xcm_app::AssetPolicy::HoldLocally,
+        }));
+        assert_ok!(crate::Tokens::deposit(asset_id, &ALICE, 10 * crate::UNIT));
+        assert_ok!(crate::Proxy::add_proxy(
+            crate::RuntimeOrigin::signed(ALICE),
+            BOB.into(),
+            crate::proxy::ProxyType::XcmAppOperator,
+            0
+        ));
+
+        let transfer = crate::RuntimeCall::Balances(pallet_balances::Call::transfer {
+            dest: BOB.into(),
+            value: 1_000_000_000,
+        });
+        assert_ok!(crate::Proxy::proxy(
+            crate::RuntimeOrigin::signed(BOB),
+            ALICE.into(),
+            None,
+            Box::new(transfer)
+        ));
+        frame_system::Pallet::<crate::Runtime>::assert_last_event(
+            pallet_proxy::Event::<crate::Runtime>::ProxyExecuted {
+                result: Err(frame_system::Error::<crate::Runtime>::CallFiltered.into()),
+            }
+            .into(),
+        );
+
+        let send_to_sora = crate::RuntimeCall::XCMApp(xcm_app::Call::send_to_sora {
+            asset_id,
+            amount: crate::UNIT,
+        });
+        assert_ok!(crate::Proxy::proxy(
+            crate::RuntimeOrigin::signed(BOB),
+            ALICE.into(),
+            None,
+            Box::new(send_to_sora)
+        ));
+        frame_system::Pallet::<crate::Runtime>::assert_last_event(
+            pallet_proxy::Event::<crate::Runtime>::ProxyExecuted { result: Ok(()) }.into(),
+        );
+        assert_eq!(crate::Tokens::free_balance(asset_id, &ALICE), 9 * crate::UNIT);
+
+        // Calls in batches are filtered as well
+        let alice_balance = SoraBalances::free_balance(&ALICE);
+        let batch = crate::RuntimeCall::Utility(pallet_utility::Call::batch {
+            calls: vec![crate::RuntimeCall::Balances(pallet_balances::Call::transfer {
+                dest: BOB.into(),
+                value: 1_000_000_000,
+            })],
+        });
+        assert_ok!(crate::Proxy::proxy(
+            crate::RuntimeOrigin::signed(BOB),
+            ALICE.into(),
+            None,
+            Box::new(batch)
+        ));
+        frame_system::Pallet::<crate::Runtime>::assert_has_event(
+            pallet_utility::Event::<crate::Runtime>::BatchInterrupted {
+                index: 0,
+                error: frame_system::Error::<crate::Runtime>::CallFiltered.into(),
+            }
+            .into(),
+        );
+        assert_eq!(SoraBalances::free_balance(&ALICE), alice_balance);
+    });
+}
+
 #[test]
 fn send_to_sora_no_mapping_error() {
     TestNet::reset();
@@ -410,6 +513,7 @@ fn send_from_sora_no_mapping_error() {
     });
 
     SoraParachain::execute_with(|| {
+        allow_destinations();
         let location = MultiLocation::new(
             1,
             X2(
@@ -418,22 +522,28 @@ fn send_from_sora_no_mapping_error() {
             ),
         );
         let assetid = relay_native_asset_id();
-        assert_noop!(
-            crate::XCMApp::transfer(
-                dispatch::RawOrigin::new(bridge_types::types::CallOriginOutput {
-                    network_id: SubNetworkId::Mainnet,
-                    additional: (),
-                    message_id: message_id(),
-                    timepoint: GenericTimepoint::Sora(1),
-                })
-                .into(),
-                assetid,
+        // The failed transfer is sent back to the sender
+        assert_ok!(crate::XCMApp::transfer(
+            dispatch::RawOrigin::new(bridge_types::types::CallOriginOutput {
+                network_id: SubNetworkId::Mainnet,
+                additional: (),
+                message_id: message_id(),
+                timepoint: GenericTimepoint::Sora(1),
+            })
+            .into(),
+            assetid,
+            ALICE,
+            xcm::VersionedMultiLocation::V3(location.clone()),
+            10000000,
+        ));
+        frame_system::Pallet::<crate::Runtime>::assert_last_event(crate::RuntimeEvent::XCMApp(
+            xcm_app::Event::AssetRefunded(
                 ALICE,
-                xcm::VersionedMultiLocation::V3(location.clone()),
+                assetid,
                 10000000,
+                orml_xtokens::Error::<crate::Runtime>::NotCrossChainTransferableCurrency.into(),
             ),
-            orml_xtokens::Error::<crate::Runtime>::NotCrossChainTransferableCurrency,
-        );
+        ));
 
         // check that assets are not transferred
         assert!(!frame_system::Pallet::<crate::Runtime>::events().iter().any(|r| matches!(
@@ -489,3 +599,627 @@ fn send_relay_chain_asset_to_sora_from_relay_asset_trapped() {
         )));
     });
 }
+
+#[test]
+fn relay_network_follows_storage() {
+    use crate::xcm_config::{
+        AccountIdToMultiLocation, LocationToAccountId, RelayNetwork, UniversalLocation,
+    };
+    use xcm_executor::traits::Convert as XcmConvert;
+
+    TestNet::reset();
+
+    SoraParachain::execute_with(|| {
+        assert_eq!(RelayNetwork::get(), NetworkId::Rococo);
+
+        for network in [NetworkId::Kusama, NetworkId::Polkadot, NetworkId::Rococo] {
+            assert_ok!(crate::XCMApp::set_relay_network(crate::RuntimeOrigin::root(), network));
+            assert_eq!(RelayNetwork::get(), network);
+            assert_eq!(UniversalLocation::get(), X2(GlobalConsensus(network), Parachain(2)));
+
+            let location = <AccountIdToMultiLocation as Convert<_, _>>::convert(ALICE);
+            assert_eq!(
+                location,
+                X1(Junction::AccountId32 { network: Some(network), id: ALICE.into() }).into()
+            );
+            assert_eq!(<LocationToAccountId as XcmConvert<_, _>>::convert(location), Ok(ALICE));
+
+            let other_network =
+                if network == NetworkId::Kusama { NetworkId::Polkadot } else { NetworkId::Kusama };
+            let foreign: MultiLocation =
+                X1(Junction::AccountId32 { network: Some(other_network), id: ALICE.into() }).into();
+            assert!(<LocationToAccountId as XcmConvert<_, AccountId32>>::convert(foreign).is_err());
+        }
+    });
+}
+
+#[test]
+fn council_majority_manages_xcm_app() {
+    TestNet::reset();
+
+    SoraParachain::execute_with(|| {
+        let majority: crate::RuntimeOrigin =
+            pallet_collective::RawOrigin::<AccountId32, crate::CouncilCollective>::Members(2, 3)
+                .into();
+        let minority: crate::RuntimeOrigin =
+            pallet_collective::RawOrigin::<AccountId32, crate::CouncilCollective>::Members(1, 3)
+                .into();
+        assert_ok!(crate::XCMApp::set_relay_network(majority, NetworkId::Kusama));
+        assert_eq!(crate::XCMApp::relay_network(), NetworkId::Kusama);
+        assert_noop!(
+            crate::XCMApp::set_relay_network(minority, NetworkId::Polkadot),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            crate::XCMApp::set_relay_network(
+                crate::RuntimeOrigin::signed(ALICE),
+                NetworkId::Polkadot
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
+fn bridge_origin(network_id: SubNetworkId) -> crate::RuntimeOrigin {
+    dispatch::RawOrigin::new(bridge_types::types::CallOriginOutput {
+        network_id,
+        additional: (),
+        message_id: message_id(),
+        timepoint: GenericTimepoint::Sora(1),
+    })
+    .into()
+}
+
+#[test]
+fn bridge_call_encoding_is_compatible() {
+    use crate::DispatchableSubstrateBridgeCall;
+    use bridge_types::substrate::{BridgeCall, XCMAppCall};
+    use codec::{Decode, Encode};
+    use parachain_common::bridge::{BridgeGovernanceCall, ParachainBridgeCall};
+
+    let xcm_app_call = XCMAppCall::Transfer {
+        asset_id: relay_native_asset_id(),
+        sender: ALICE,
+        recipient: xcm::VersionedMultiLocation::V3(MultiLocation::parent()),
+        amount: 100,
+    };
+    let encoded = BridgeCall::XCMApp(xcm_app_call.clone()).encode();
+    let call = DispatchableSubstrateBridgeCall::decode(&mut &encoded[..]).unwrap();
+    assert_eq!(call, DispatchableSubstrateBridgeCall(ParachainBridgeCall::XCMApp(xcm_app_call)));
+    assert_eq!(call.encode(), encoded);
+
+    let substrate_app_call = SubstrateAppCall::Transfer {
+        asset_id: relay_native_asset_id(),
+        sender: None,
+        recipient: BOB,
+        amount: 100,
+    };
+    assert_eq!(
+        ParachainBridgeCall::SubstrateApp(substrate_app_call.clone()).encode(),
+        BridgeCall::SubstrateApp(substrate_app_call).encode()
+    );
+
+    let call = ParachainBridgeCall::Governance {
+        sender: ALICE,
+        call: BridgeGovernanceCall::ForceDefaultXcmVersion { version: Some(2) },
+    };
+    let encoded = call.encode();
+    // Layout SORA has to encode the governance calls with
+    let mut expected = vec![4];
+    expected.extend_from_slice(&<[u8; 32]>::from(ALICE));
+    expected.extend_from_slice(&[2, 1, 2, 0, 0, 0]);
+    assert_eq!(encoded, expected);
+    assert_eq!(ParachainBridgeCall::decode(&mut &encoded[..]).unwrap(), call);
+
+    let call = ParachainBridgeCall::XCMAppRemote(xcm_app::XCMAppRemoteCall::Transact {
+        sender: ALICE,
+        destination: xcm::VersionedMultiLocation::V3(MultiLocation::new(1, X1(Parachain(1)))),
+        fee_asset_id: para_x_asset_id(),
+        fee_amount: 100,
+        require_weight_at_most: frame_support::weights::Weight::from_parts(1_000_000, 1024),
+        call: vec![0, 1],
+    });
+    let encoded = call.encode();
+    // Layout SORA has to encode the remote calls with
+    let mut expected = vec![5, 0];
+    expected.extend_from_slice(&<[u8; 32]>::from(ALICE));
+    expected.extend_from_slice(&[3, 1, 1, 0, 4]);
+    expected.extend_from_slice(para_x_asset_id().as_bytes());
+    expected.extend_from_slice(&100u128.to_le_bytes());
+    expected.extend_from_slice(&[0x02, 0x09, 0x3d, 0x00, 0x01, 0x10]);
+    expected.extend_from_slice(&[8, 0, 1]);
+    assert_eq!(encoded, expected);
+    assert_eq!(ParachainBridgeCall::decode(&mut &encoded[..]).unwrap(), call);
+
+    let call = ParachainBridgeCall::XCMAppTransactReport(xcm_app::TransactReport {
+        sender: ALICE,
+        query_id: 1,
+        error: None,
+    });
+    let encoded = call.encode();
+    // Layout SORA has to decode the transact reports with
+    let mut expected = vec![6];
+    expected.extend_from_slice(&<[u8; 32]>::from(ALICE));
+    expected.extend_from_slice(&1u64.to_le_bytes());
+    expected.push(0);
+    assert_eq!(encoded, expected);
+    assert_eq!(ParachainBridgeCall::decode(&mut &encoded[..]).unwrap(), call);
+}
+
+#[test]
+fn sora_account_transacts_on_sibling() {
+    use crate::{DispatchableSubstrateBridgeCall, SubstrateBridgeCallFilter};
+    use frame_support::{dispatch::Dispatchable, traits::Contains};
+    use parachain_common::bridge::ParachainBridgeCall;
+
+    TestNet::reset();
+    prepare_sora_parachain();
+
+    SoraParachain::execute_with(|| {
+        let destination = MultiLocation::new(1, X1(Parachain(1)));
+        let call = DispatchableSubstrateBridgeCall(ParachainBridgeCall::XCMAppRemote(
+            xcm_app::XCMAppRemoteCall::Transact {
+                sender: ALICE,
+                destination: xcm::VersionedMultiLocation::V3(destination),
+                fee_asset_id: para_x_asset_id(),
+                fee_amount: 1_000_000_000,
+                require_weight_at_most: frame_support::weights::Weight::from_parts(1_000_000, 1024),
+                call: vec![0, 1],
+            },
+        ));
+        assert!(SubstrateBridgeCallFilter::contains(&call));
+        assert_noop!(
+            call.clone().dispatch(crate::RuntimeOrigin::signed(ALICE)),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(call.dispatch(bridge_origin(SubNetworkId::Mainnet)));
+        assert_eq!(crate::XCMApp::pending_transact(0), Some((sora_alice(), destination)));
+        frame_system::Pallet::<crate::Runtime>::assert_has_event(
+            xcm_app::Event::<crate::Runtime>::TransactSent(sora_alice(), destination, 0).into(),
+        );
+    });
+}
+
+#[test]
+fn sora_governance_dispatches_root_calls() {
+    use crate::{
+        DispatchableSubstrateBridgeCall, SoraGovernanceAccount, SubstrateBridgeCallFilter,
+    };
+    use frame_support::{dispatch::Dispatchable, traits::Contains};
+    use parachain_common::bridge::{BridgeGovernanceCall, ParachainBridgeCall};
+
+    TestNet::reset();
+
+    SoraParachain::execute_with(|| {
+        let governance_call = |sender| {
+            DispatchableSubstrateBridgeCall(ParachainBridgeCall::Governance {
+                sender,
+                call: BridgeGovernanceCall::ForceDefaultXcmVersion { version: Some(2) },
+            })
+        };
+        let call = governance_call(ALICE);
+        assert!(SubstrateBridgeCallFilter::contains(&call));
+
+        // Rejected until the governance account is set, whatever the sender
+        assert_noop!(
+            call.clone().dispatch(bridge_origin(SubNetworkId::Mainnet)),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            governance_call(AccountId32::new([0; 32]))
+                .dispatch(bridge_origin(SubNetworkId::Mainnet)),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        SoraGovernanceAccount::set(&Some(ALICE));
+        assert_noop!(
+            call.clone().dispatch(crate::RuntimeOrigin::signed(ALICE)),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            call.clone().dispatch(bridge_origin(SubNetworkId::Kusama)),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            governance_call(BOB).dispatch(bridge_origin(SubNetworkId::Mainnet)),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(call.dispatch(bridge_origin(SubNetworkId::Mainnet)));
+        // Destinations with unknown version now get XCM v2
+        let unknown_destination = MultiLocation::new(1, X1(Parachain(999)));
+        assert!(matches!(
+            <crate::PolkadotXcm as xcm::WrapVersion>::wrap_version::<()>(
+                &unknown_destination,
+                Xcm(vec![])
+            ),
+            Ok(xcm::VersionedXcm::V2(_))
+        ));
+    });
+}
+
+#[test]
+fn technical_committee_enters_maintenance_mode() {
+    TestNet::reset();
+
+    SoraParachain::execute_with(|| {
+        let majority: crate::RuntimeOrigin =
+            pallet_collective::RawOrigin::<AccountId32, crate::TechnicalCollective>::Members(2, 3)
+                .into();
+        assert_noop!(
+            crate::MaintenanceMode::enter_maintenance_mode(crate::RuntimeOrigin::signed(ALICE)),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(crate::MaintenanceMode::enter_maintenance_mode(majority));
+        assert!(crate::MaintenanceMode::maintenance_mode());
+    });
+}
+
+#[test]
+fn maintenance_mode_filters_user_calls() {
+    use frame_support::dispatch::Dispatchable;
+
+    TestNet::reset();
+
+    SoraParachain::execute_with(|| {
+        crate::Balances::make_free_balance_be(&ALICE, crate::UNIT);
+        let transfer = crate::RuntimeCall::Balances(pallet_balances::Call::transfer {
+            dest: BOB.into(),
+            value: 1_000_000_000,
+        });
+        assert_ok!(crate::MaintenanceMode::enter_maintenance_mode(crate::RuntimeOrigin::root()));
+        assert_noop!(
+            transfer.clone().dispatch(crate::RuntimeOrigin::signed(ALICE)),
+            frame_system::Error::<crate::Runtime>::CallFiltered
+        );
+        assert_ok!(crate::RuntimeCall::System(frame_system::Call::remark { remark: vec![] })
+            .dispatch(crate::RuntimeOrigin::signed(ALICE)));
+
+        assert_ok!(crate::MaintenanceMode::resume_normal_operation(crate::RuntimeOrigin::root()));
+        assert_ok!(transfer.dispatch(crate::RuntimeOrigin::signed(ALICE)));
+    });
+}
+
+#[test]
+fn fees_are_paid_in_mapped_asset() {
+    use asset_fee_payment::ChargeAssetTxPayment;
+    use frame_support::{dispatch::DispatchInfo, weights::Weight};
+    use sp_runtime::{traits::SignedExtension, FixedU128};
+
+    TestNet::reset();
+    prepare_sora_parachain();
+
+    SoraParachain::execute_with(|| {
+        let asset_id = relay_native_asset_id();
+        let call = crate::RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+        let info = DispatchInfo { weight: Weight::from_ref_time(1_000_000), ..Default::default() };
+        assert_ok!(crate::Tokens::deposit(asset_id, &ALICE, 10 * crate::UNIT));
+        assert!(ChargeAssetTxPayment::<crate::Runtime>::from(0, Some(asset_id))
+            .pre_dispatch(&ALICE, &call, &info, 100)
+            .is_err());
+
+        assert_ok!(crate::AssetFeePayment::set_fee_rate(
+            crate::RuntimeOrigin::root(),
+            asset_id,
+            Some(FixedU128::from(2))
+        ));
+        // Fees in the assets which are not held on the parachain would be sent to SORA
+        assert!(ChargeAssetTxPayment::<crate::Runtime>::from(0, Some(asset_id))
+            .pre_dispatch(&ALICE, &call, &info, 100)
+            .is_err());
+
+        assert_ok!(crate::XCMApp::do_apply_change(xcm_app::DelayedChange::SetAssetPolicy {
+            asset_id,
+            policy: xcm_app::AssetPolicy::HoldLocally,
+        }));
+        let fee = crate::TransactionPayment::compute_fee(100, &info, 0);
+        let pre = ChargeAssetTxPayment::<crate::Runtime>::from(0, Some(asset_id))
+            .pre_dispatch(&ALICE, &call, &info, 100)
+            .unwrap();
+        assert_ok!(ChargeAssetTxPayment::<crate::Runtime>::post_dispatch(
+            Some(pre),
+            &info,
+            &Default::default(),
+            100,
+            &Ok(())
+        ));
+        assert_eq!(crate::Tokens::free_balance(asset_id, &ALICE), 10 * crate::UNIT - 2 * fee);
+        let to_treasury = crate::impls::TREASURY_FEE_SHARE * (2 * fee);
+        assert_eq!(
+            crate::Tokens::free_balance(asset_id, &crate::Treasury::account_id()),
+            to_treasury
+        );
+        assert_eq!(
+            crate::Tokens::free_balance(asset_id, &crate::CollatorSelection::account_id()),
+            2 * fee - to_treasury
+        );
+    });
+}
+
+#[test]
+fn sibling_asset_held_locally_and_sent_back() {
+    TestNet::reset();
+
+    Relay::execute_with(|| {
+        let _ = RelayBalances::deposit_creating(&para_x_account(), 1000000000000000000);
+    });
+
+    prepare_sora_parachain();
+
+    SoraParachain::execute_with(|| {
+        assert_ok!(crate::XCMApp::do_apply_change(xcm_app::DelayedChange::SetAssetPolicy {
+            asset_id: para_x_asset_id(),
+            policy: xcm_app::AssetPolicy::HoldLocally,
+        }));
+    });
+
+    ParaX::execute_with(|| {
+        let _ = ParaTokens::set_balance(
+            para_x::RuntimeOrigin::root(),
+            ALICE,
+            CurrencyId::X,
+            999999999999999999999,
+            0,
+        );
+        assert_ok!(ParaXTokens::transfer(
+            Some(ALICE).into(),
+            CurrencyId::X,
+            10000000000000000,
+            Box::new(
+                MultiLocation::new(
+                    1,
+                    X2(
+                        Parachain(2),
+                        Junction::AccountId32 { network: Some(NetworkId::Rococo), id: BOB.into() }
+                    )
+                )
+                .into()
+            ),
+            WeightLimit::Unlimited
+        ));
+    });
+
+    SoraParachain::execute_with(|| {
+        assert_eq!(crate::Tokens::free_balance(para_x_asset_id(), &BOB), 9999996000000000);
+        assert!(!frame_system::Pallet::<crate::Runtime>::events().iter().any(|r| matches!(
+            r.event,
+            crate::RuntimeEvent::XCMApp(xcm_app::Event::AssetAddedToChannel(_))
+        )));
+
+        assert_ok!(crate::XCMApp::send_to_parachain(
+            crate::RuntimeOrigin::signed(BOB),
+            para_x_asset_id(),
+            xcm::VersionedMultiLocation::V3(MultiLocation::new(
+                1,
+                X2(
+                    Parachain(1),
+                    Junction::AccountId32 { network: Some(NetworkId::Rococo), id: ALICE.into() }
+                )
+            )),
+            5000000000000000
+        ));
+        assert_eq!(crate::Tokens::free_balance(para_x_asset_id(), &BOB), 4999996000000000);
+    });
+
+    ParaX::execute_with(|| {
+        assert!(ParaTokens::free_balance(CurrencyId::X, &ALICE) > 999989999999999999999);
+    });
+}
+
+#[test]
+fn send_native_token_to_sibling_and_back() {
+    TestNet::reset();
+
+    prepare_sora_parachain();
+
+    let (alice_balance, bob_balance) = SoraParachain::execute_with(|| {
+        (SoraBalances::free_balance(&ALICE), SoraBalances::free_balance(&BOB))
+    });
+
+    SoraParachain::execute_with(|| {
+        assert_ok!(crate::PolkadotXcm::limited_reserve_transfer_assets(
+            crate::RuntimeOrigin::signed(ALICE),
+            Box::new((Parent, Parachain(1)).into()),
+            Box::new(
+                MultiLocation::new(0, X1(Junction::AccountId32 { network: None, id: BOB.into() }))
+                    .into()
+            ),
+            Box::new(MultiAsset::from((Here, 1_000_000_000_000_000)).into()),
+            0,
+            WeightLimit::Unlimited
+        ));
+        assert_eq!(SoraBalances::free_balance(&ALICE), alice_balance - 1_000_000_000_000_000);
+        assert_eq!(SoraBalances::free_balance(&para_x_sibling_account()), 1_000_000_000_000_000);
+    });
+
+    ParaX::execute_with(|| {
+        assert_eq!(ParaTokens::free_balance(CurrencyId::S, &BOB), 999_999_999_999_960);
+        assert_ok!(ParaXTokens::transfer(
+            Some(BOB).into(),
+            CurrencyId::S,
+            500_000_000_000_000,
+            Box::new(
+                MultiLocation::new(
+                    1,
+                    X2(
+                        Parachain(2),
+                        Junction::AccountId32 { network: Some(NetworkId::Rococo), id: BOB.into() }
+                    )
+                )
+                .into()
+            ),
+            WeightLimit::Unlimited
+        ));
+    });
+
+    SoraParachain::execute_with(|| {
+        assert_eq!(SoraBalances::free_balance(&para_x_sibling_account()), 500_000_000_000_000);
+        assert!(SoraBalances::free_balance(&BOB) > bob_balance);
+        assert!(SoraBalances::free_balance(&BOB) < bob_balance + 500_000_000_000_000);
+    });
+}
+
+#[test]
+fn mapped_assets_are_not_reserve_transferred_with_pallet_xcm() {
+    TestNet::reset();
+
+    prepare_sora_parachain();
+
+    SoraParachain::execute_with(|| {
+        assert_noop!(
+            crate::PolkadotXcm::limited_reserve_transfer_assets(
+                crate::RuntimeOrigin::signed(ALICE),
+                Box::new((Parent, Parachain(1)).into()),
+                Box::new(
+                    MultiLocation::new(
+                        0,
+                        X1(Junction::AccountId32 { network: None, id: BOB.into() })
+                    )
+                    .into()
+                ),
+                Box::new(MultiAsset::from((Parent, 1_000_000_000_000)).into()),
+                0,
+                WeightLimit::Unlimited
+            ),
+            pallet_xcm::Error::<crate::Runtime>::Filtered
+        );
+    });
+}
+
+#[test]
+fn only_root_sends_xcm_with_pallet_xcm() {
+    TestNet::reset();
+
+    prepare_sora_parachain();
+
+    SoraParachain::execute_with(|| {
+        let message = || Box::new(xcm::VersionedXcm::V3(Xcm(vec![ClearOrigin])));
+        assert_noop!(
+            crate::PolkadotXcm::send(
+                crate::RuntimeOrigin::signed(ALICE),
+                Box::new(Parent.into()),
+                message()
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(crate::PolkadotXcm::send(
+            crate::RuntimeOrigin::root(),
+            Box::new(Parent.into()),
+            message()
+        ));
+    });
+}
+
+#[test]
+fn sovereign_accounts_of_reserve_chains() {
+    TestNet::reset();
+
+    prepare_sora_parachain();
+
+    SoraParachain::execute_with(|| {
+        let _ = SoraBalances::deposit_creating(&para_x_sibling_account(), 1_000_000_000_000_000);
+        let mut accounts = crate::impls::reserve_sovereign_accounts();
+        accounts.sort_by_key(|info| info.asset_id);
+        assert_eq!(accounts.len(), 2);
+
+        assert_eq!(accounts[0].asset_id, para_x_asset_id());
+        assert_eq!(accounts[0].reserve, MultiLocation::new(1, X1(Parachain(1))).into());
+        assert_eq!(accounts[0].account, para_x_sibling_account());
+        assert_eq!(accounts[0].native_balance, 1_000_000_000_000_000);
+        assert_eq!(accounts[0].asset_balance, 0);
+
+        assert_eq!(accounts[1].asset_id, relay_native_asset_id());
+        assert_eq!(accounts[1].reserve, MultiLocation::parent().into());
+        assert_eq!(accounts[1].native_balance, 0);
+    });
+}
+
+#[test]
+fn sora_accounts_are_derived_per_network() {
+    use crate::xcm_config::{AccountIdToMultiLocation, LocationToAccountId};
+    use sp_runtime::traits::Convert;
+    use xcm_executor::traits::Convert as XcmConvert;
+
+    TestNet::reset();
+
+    SoraParachain::execute_with(|| {
+        let location = xcm_app::sora_account_location(SubNetworkId::Mainnet, ALICE);
+        assert_eq!(
+            <LocationToAccountId as XcmConvert<_, _>>::convert_ref(&location),
+            Ok(sora_alice())
+        );
+        assert_ne!(sora_alice(), ALICE);
+        assert_ne!(sora_alice(), crate::XCMApp::sora_account_id(SubNetworkId::Kusama, ALICE));
+        // Converting the location does not register the account
+        assert!(crate::XCMApp::sora_account(sora_alice()).is_none());
+
+        crate::XCMApp::register_sora_account(SubNetworkId::Mainnet, ALICE);
+        assert_eq!(<AccountIdToMultiLocation as Convert<_, _>>::convert(sora_alice()), location);
+        assert_eq!(
+            <AccountIdToMultiLocation as Convert<_, _>>::convert(BOB),
+            MultiLocation::new(
+                0,
+                X1(Junction::AccountId32 { network: Some(NetworkId::Rococo), id: BOB.into() })
+            )
+        );
+    });
+}
+
+#[test]
+fn send_relay_chain_asset_to_evm_sibling() {
+    TestNet::reset();
+
+    Relay::execute_with(|| {
+        let _ = RelayBalances::deposit_creating(&sora_para_account(), 1000000000000000000);
+    });
+
+    prepare_sora_parachain();
+
+    let evm_account = sp_core::H160::from([7; 20]);
+    SoraParachain::execute_with(|| {
+        let evm_chain = MultiLocation::new(1, X1(Parachain(PARA_EVM_ID)));
+        let location = MultiLocation::new(
+            1,
+            X2(Parachain(PARA_EVM_ID), AccountKey20 { network: None, key: evm_account.into() }),
+        );
+        let assetid = relay_native_asset_id();
+        // Rejected transfers from SORA are sent back to the sender
+        assert_ok!(crate::XCMApp::transfer(
+            bridge_origin(SubNetworkId::Mainnet),
+            assetid,
+            ALICE,
+            xcm::VersionedMultiLocation::V3(location),
+            1_000_000_000_000,
+        ));
+        frame_system::Pallet::<crate::Runtime>::assert_last_event(crate::RuntimeEvent::XCMApp(
+            xcm_app::Event::AssetRefunded(
+                ALICE,
+                assetid,
+                1_000_000_000_000,
+                xcm_app::Error::<crate::Runtime>::InvalidBeneficiary.into(),
+            ),
+        ));
+
+        assert_ok!(crate::XCMApp::do_apply_change(xcm_app::DelayedChange::SetBeneficiaryKind {
+            destination: evm_chain,
+            kind: xcm_app::BeneficiaryKind::AccountKey20,
+        }));
+        assert_noop!(
+            crate::XCMApp::ensure_valid_recipient_location(&MultiLocation::new(
+                1,
+                X2(Parachain(PARA_EVM_ID), Junction::AccountId32 { network: None, id: BOB.into() })
+            )),
+            xcm_app::Error::<crate::Runtime>::InvalidBeneficiary
+        );
+        assert_ok!(crate::XCMApp::transfer(
+            bridge_origin(SubNetworkId::Mainnet),
+            assetid,
+            ALICE,
+            xcm::VersionedMultiLocation::V3(location),
+            1_000_000_000_000,
+        ));
+    });
+
+    ParaEvm::execute_with(|| {
+        let balance = ParaEvmBalances::free_balance(&evm_account);
+        assert!(balance > 0 && balance < 1_000_000_000_000);
+    });
+}